@@ -0,0 +1,36 @@
+import os
+
+from pack_localization import is_japanese, load_maps, save_packs, save_rust_file
+
+TAGS_FILE = "ja_tags.txt"
+
+def load_tags() -> dict:
+    """Load the curated Japanese tag names."""
+    base_dir = os.path.dirname(os.path.realpath(__file__))
+    tags = {}
+    with open(os.path.join(base_dir, TAGS_FILE), encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            english, japanese = line.split("=", 1)
+            tags[english.strip().lower()] = japanese.strip()
+    return tags
+
+def japanese_names(names: dict, namespace: str) -> dict:
+    """Keep the names written in Japanese script."""
+    return {k: v for k, v in names.items() if is_japanese(v, namespace)}
+
+if __name__ == "__main__":
+    cn = load_maps("cn")
+    data = {
+        "tag": load_tags(),
+        "parody": japanese_names(cn.get("parody", {}), "parody"),
+    }
+    # character, group and artist names are read from the Chinese pack
+    for key, val in data.items():
+        print(f"  {key}: {len(val)} entries")
+
+    save_packs("ja", data)
+    save_rust_file("ja", "generate_localization_ja.py")
+    print("Done.")
@@ -0,0 +1,69 @@
+import os
+
+TABLE_FILE = "zh_hant_chars.txt"
+
+def load_table() -> dict:
+    """Load the Simplified -> Traditional character table."""
+    base_dir = os.path.dirname(os.path.realpath(__file__))
+    table = {}
+    with open(os.path.join(base_dir, TABLE_FILE), encoding="utf-8") as f:
+        for line in f:
+            if line.startswith("#"):
+                continue
+            for pair in line.split():
+                simplified, traditional = pair
+                table[simplified] = traditional
+    return table
+
+def validate_table(table: dict):
+    """Make sure the table can be inverted without ambiguity."""
+    traditional = list(table.values())
+    duplicates = {c for c in traditional if traditional.count(c) > 1}
+    if duplicates:
+        raise ValueError(f"Traditional characters mapped more than once: {''.join(sorted(duplicates))}")
+    overlap = set(traditional) & set(table.keys())
+    if overlap:
+        raise ValueError(f"Characters used as both Simplified and Traditional: {''.join(sorted(overlap))}")
+
+def invert_table(table: dict) -> dict:
+    """Traditional -> Simplified table."""
+    return {t: s for s, t in table.items()}
+
+def fold(text: str, inverse: dict) -> str:
+    """Fold Traditional characters to Simplified, matching `to_simplified` in models.rs."""
+    return "".join(inverse.get(c, c) for c in text)
+
+def generate_char_map(name: str, data: dict) -> str:
+    """Generate a phf_map! of characters."""
+    lines = [f"pub static {name}: phf::Map<char, char> = phf::phf_map! {{"]
+    for key, value in sorted(data.items()):
+        lines.append(f"    '{key}' => '{value}',")
+    lines.append("};")
+    return "\n".join(lines)
+
+def generate_rust_file(table: dict) -> str:
+    """Generate the complete Rust source file."""
+    lines = [
+        "// This file is auto-generated by generate_zh_hant.py",
+        "// Do not edit manually!",
+        "",
+        "// Simplified -> Traditional, for display",
+        generate_char_map("ZH_HANT", table),
+        "",
+        "// Traditional -> Simplified, for search input",
+        generate_char_map("ZH_HANS", invert_table(table)),
+        "",
+    ]
+    return "\n".join(lines)
+
+if __name__ == "__main__":
+    table = load_table()
+    validate_table(table)
+    print(f"  {len(table)} character pairs")
+
+    base_dir = os.path.dirname(os.path.realpath(__file__))
+    file_path = os.path.join(base_dir, "..", "src", "localization_zh_hant.rs")
+    print(f"Saving to {file_path}...")
+    with open(file_path, "w", encoding="utf-8") as f:
+        f.write(generate_rust_file(table))
+    print("Done.")
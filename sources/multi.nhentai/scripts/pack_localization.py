@@ -0,0 +1,294 @@
+"""Pack localization maps into compressed blobs read by `packed.rs`.
+
+A blob is a little-endian u32 holding the unpacked size, followed by LZ77
+tokens:
+
+    0xxxxxxx            literal run of x + 1 bytes, which follow
+    1xxxxxxx lo hi      copy x + 3 bytes from `hi << 8 | lo` bytes back
+
+The unpacked payload is a sorted string pool shared by all tables in the
+blob, each string stored as the bytes it doesn't share with the one before,
+followed by the tables as (key, value) index pairs sorted by key, with each
+key stored as the difference from the previous one. The value index is
+shifted left by one, with the low bit set when the localized name is written
+in Japanese script:
+
+    varint count, count * (varint shared, varint length, utf-8 bytes)
+    varint count, count * (varint length, length * (varint key, varint value << 1 | japanese))
+"""
+
+import os
+import re
+
+from generate_zh_hant import fold, invert_table, load_table
+
+PACK_DIR = os.path.join("..", "src", "localization")
+
+MIN_MATCH = 3
+MAX_MATCH = 0x7f + MIN_MATCH
+MAX_LITERAL = 0x80
+WINDOW = 0xffff
+# Candidates tried per position, trading packing speed for size
+MAX_CHAIN = 32
+
+# Tables gated by each feature, in the order `models.rs` names them
+TAG_NAMESPACES = ["tag", "parody"]
+NAME_NAMESPACES = ["character", "group", "artist"]
+GROUPS = {"tags": TAG_NAMESPACES, "names": NAME_NAMESPACES}
+# Groups read from another language's pack, keeping the entries written in
+# Japanese script, so the strings aren't stored twice
+SHARED_GROUPS = {"ja": {"names": "cn"}}
+
+KANA_PATTERN = re.compile(r'[぀-ヿ]')
+KANJI_PATTERN = re.compile(r'[一-鿿]')
+# Namespaces whose kanji-only names are mostly Japanese
+KANJI_NAMESPACES = {"group", "artist"}
+
+def is_shift_jis(s: str) -> bool:
+    try:
+        s.encode("shift_jis")
+        return True
+    except UnicodeEncodeError:
+        return False
+
+def is_japanese(name: str, namespace: str) -> bool:
+    """Whether a localized name is written in Japanese script.
+
+    Names with kana always are. Kanji-only group and artist names are too
+    when they only use characters found in Shift_JIS, which rules out most
+    Simplified Chinese translations.
+    """
+    if KANA_PATTERN.search(name):
+        return True
+    return namespace in KANJI_NAMESPACES and bool(KANJI_PATTERN.search(name)) and is_shift_jis(name)
+
+def write_varint(out: bytearray, value: int):
+    while value >= 0x80:
+        out.append(value & 0x7f | 0x80)
+        value >>= 7
+    out.append(value)
+
+def read_varint(data: bytes, pos: int) -> tuple:
+    value = shift = 0
+    while True:
+        byte = data[pos]
+        pos += 1
+        value |= (byte & 0x7f) << shift
+        if byte < 0x80:
+            return value, pos
+        shift += 7
+
+def pack_tables(tables: list) -> bytes:
+    """Serialize maps of key -> (value, japanese) into one payload sharing a string pool."""
+    strings = sorted({s for table in tables for key, (value, _) in table.items() for s in (key, value)})
+    index = {s: i for i, s in enumerate(strings)}
+
+    out = bytearray()
+    write_varint(out, len(strings))
+    previous = b""
+    for s in strings:
+        encoded = s.encode("utf-8")
+        shared = 0
+        while shared < min(len(previous), len(encoded)) and previous[shared] == encoded[shared]:
+            shared += 1
+        write_varint(out, shared)
+        write_varint(out, len(encoded) - shared)
+        out += encoded[shared:]
+        previous = encoded
+    write_varint(out, len(tables))
+    for table in tables:
+        write_varint(out, len(table))
+        # code point order matches the byte order `str::cmp` uses, so keys ascend
+        previous = 0
+        for key, (value, japanese) in sorted(table.items()):
+            write_varint(out, index[key] - previous)
+            write_varint(out, index[value] << 1 | japanese)
+            previous = index[key]
+    return bytes(out)
+
+def unpack_tables(data: bytes) -> list:
+    count, pos = read_varint(data, 0)
+    strings = []
+    previous = b""
+    for _ in range(count):
+        shared, pos = read_varint(data, pos)
+        length, pos = read_varint(data, pos)
+        previous = previous[:shared] + data[pos:pos + length]
+        strings.append(previous.decode("utf-8"))
+        pos += length
+    tables = []
+    count, pos = read_varint(data, pos)
+    for _ in range(count):
+        length, pos = read_varint(data, pos)
+        table = {}
+        key = 0
+        for _ in range(length):
+            delta, pos = read_varint(data, pos)
+            value, pos = read_varint(data, pos)
+            key += delta
+            table[strings[key]] = (strings[value >> 1], bool(value & 1))
+        tables.append(table)
+    return tables
+
+def compress(data: bytes) -> bytes:
+    """Greedy LZ77 with hash chains over 3-byte prefixes."""
+    out = bytearray(len(data).to_bytes(4, "little"))
+    literals = bytearray()
+    chains = {}
+
+    def flush_literals():
+        for start in range(0, len(literals), MAX_LITERAL):
+            run = literals[start:start + MAX_LITERAL]
+            out.append(len(run) - 1)
+            out.extend(run)
+        literals.clear()
+
+    def remember(pos: int):
+        if pos + MIN_MATCH <= len(data):
+            chain = chains.setdefault(data[pos:pos + MIN_MATCH], [])
+            chain.append(pos)
+            if len(chain) > MAX_CHAIN:
+                del chain[0]
+
+    pos = 0
+    while pos < len(data):
+        best_length = best_distance = 0
+        limit = min(MAX_MATCH, len(data) - pos)
+        for candidate in reversed(chains.get(data[pos:pos + MIN_MATCH], [])):
+            distance = pos - candidate
+            if distance > WINDOW:
+                break
+            length = 0
+            while length < limit and data[candidate + length] == data[pos + length]:
+                length += 1
+            if length > best_length:
+                best_length, best_distance = length, distance
+                if length == limit:
+                    break
+        if best_length >= MIN_MATCH:
+            flush_literals()
+            out.append(0x80 | best_length - MIN_MATCH)
+            out += best_distance.to_bytes(2, "little")
+            for skipped in range(pos, pos + best_length):
+                remember(skipped)
+            pos += best_length
+        else:
+            literals.append(data[pos])
+            remember(pos)
+            pos += 1
+    flush_literals()
+    return bytes(out)
+
+def decompress(blob: bytes) -> bytes:
+    size = int.from_bytes(blob[:4], "little")
+    out = bytearray()
+    pos = 4
+    while pos < len(blob):
+        token = blob[pos]
+        pos += 1
+        if token < 0x80:
+            out += blob[pos:pos + token + 1]
+            pos += token + 1
+        else:
+            distance = int.from_bytes(blob[pos:pos + 2], "little")
+            pos += 2
+            for _ in range((token & 0x7f) + MIN_MATCH):
+                out.append(out[-distance])
+    assert len(out) == size
+    return bytes(out)
+
+def pack_path(filename: str) -> str:
+    base_dir = os.path.dirname(os.path.realpath(__file__))
+    return os.path.join(base_dir, PACK_DIR, filename)
+
+def save_pack(filename: str, tables: list):
+    payload = pack_tables(tables)
+    blob = compress(payload)
+    assert unpack_tables(decompress(blob)) == [dict(sorted(t.items())) for t in tables]
+    os.makedirs(os.path.dirname(pack_path(filename)), exist_ok=True)
+    with open(pack_path(filename), "wb") as f:
+        f.write(blob)
+    print(f"Saved {filename}: {len(payload)} bytes packed to {len(blob)}")
+
+def load_pack(filename: str) -> list:
+    with open(pack_path(filename), "rb") as f:
+        return unpack_tables(decompress(f.read()))
+
+def forward_map(names: dict, namespace: str) -> dict:
+    """English -> localized, flagging the names in Japanese script."""
+    return {k: (v, is_japanese(v, namespace)) for k, v in names.items()}
+
+def reverse_map(names: dict, namespace: str, simplified: dict) -> dict:
+    """Localized -> English, keyed by the folded Simplified form so Traditional input matches too."""
+    return {fold(v.lower(), simplified): (k, is_japanese(v, namespace)) for k, v in names.items()}
+
+def save_packs(lang: str, data: dict):
+    """Pack the forward and reverse maps of one language, one blob per feature."""
+    simplified = invert_table(load_table())
+    for group, namespaces in GROUPS.items():
+        if group in SHARED_GROUPS.get(lang, {}):
+            continue
+        names = [data.get(namespace, {}) for namespace in namespaces]
+        forward = [forward_map(table, namespace) for table, namespace in zip(names, namespaces)]
+        reverse = [reverse_map(table, namespace, simplified) for table, namespace in zip(names, namespaces)]
+        save_pack(f"{lang}_{group}.bin", forward + reverse)
+
+def load_maps(lang: str) -> dict:
+    """Read the forward maps of one language back out of its blobs."""
+    maps = {}
+    for group, namespaces in GROUPS.items():
+        tables = load_pack(f"{lang}_{group}.bin")
+        for namespace, table in zip(namespaces, tables):
+            maps[namespace] = {k: v for k, (v, _) in table.items()}
+    return maps
+
+def generate_rust_file(lang: str, generator: str) -> str:
+    """Generate the module declaring the tables of one language."""
+    prefix = lang.upper()
+    lines = [
+        f"// This file is auto-generated by {generator}",
+        "// Do not edit manually!",
+        "",
+        "use crate::packed::{Pack, Table};",
+    ]
+    for group, namespaces in GROUPS.items():
+        static = group.upper()
+        feature = f"{lang}-{group}"
+        source = SHARED_GROUPS.get(lang, {}).get(group)
+        if source:
+            lines += [
+                "",
+                f"// the names in the {source} pack written in Japanese script",
+                f'#[cfg(feature = "{feature}")]',
+                f"use crate::localization_{source}::{static};",
+            ]
+            constructor = "Table::japanese"
+            visibility = ""
+        else:
+            shared = any(shared.get(group) == lang for shared in SHARED_GROUPS.values())
+            visibility = "pub " if shared else ""
+            lines += [
+                "",
+                f'#[cfg(feature = "{feature}")]',
+                f'{visibility}static {static}: Pack = Pack::new(include_bytes!("localization/{lang}_{group}.bin"));',
+            ]
+            constructor = "Table::new"
+        lines += [
+            f'#[cfg(not(feature = "{feature}"))]',
+            f"{visibility}static {static}: Pack = Pack::new(&[]);",
+            "",
+        ]
+        for i, namespace in enumerate(namespaces):
+            lines.append(f"pub static {prefix}_{namespace.upper()}: Table = {constructor}(&{static}, {i});")
+        for i, namespace in enumerate(namespaces):
+            index = len(namespaces) + i
+            lines.append(f"pub static {prefix}_{namespace.upper()}_REVERSE: Table = {constructor}(&{static}, {index});")
+    lines.append("")
+    return "\n".join(lines)
+
+def save_rust_file(lang: str, generator: str):
+    base_dir = os.path.dirname(os.path.realpath(__file__))
+    file_path = os.path.join(base_dir, "..", "src", f"localization_{lang}.rs")
+    print(f"Saving to {file_path}...")
+    with open(file_path, "w", encoding="utf-8") as f:
+        f.write(generate_rust_file(lang, generator))
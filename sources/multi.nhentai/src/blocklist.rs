@@ -0,0 +1,229 @@
+use crate::{
+	models::{NHentaiTag, reverse_translate_name, reverse_translate_tag},
+	query::{Namespace, SearchQuery},
+	settings::AllowlistMode,
+};
+use aidoku::alloc::{String, Vec};
+use core::fmt::{self, Write};
+
+/// A tag name to match, either exactly or as a glob where `*` matches any
+/// run of characters and `?` matches a single character
+#[derive(Debug, Clone, PartialEq)]
+enum NamePattern {
+	Exact(String),
+	Glob(Vec<char>),
+}
+
+impl NamePattern {
+	fn parse(name: &str, namespace: Option<Namespace>) -> Self {
+		let name = name.trim().trim_matches('"');
+		if name.contains(['*', '?']) {
+			return Self::Glob(name.to_lowercase().chars().collect());
+		}
+		let name = match namespace {
+			None | Some(Namespace::Tag) => reverse_translate_tag(name),
+			Some(namespace) => reverse_translate_name(name, namespace),
+		};
+		Self::Exact(name.to_lowercase())
+	}
+
+	fn matches(&self, name: &str) -> bool {
+		match self {
+			Self::Exact(exact) => name.eq_ignore_ascii_case(exact),
+			Self::Glob(pattern) => {
+				let name = name.to_lowercase().chars().collect::<Vec<_>>();
+				glob_matches(pattern, &name)
+			}
+		}
+	}
+}
+
+impl fmt::Display for NamePattern {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Exact(name) => f.write_str(name),
+			Self::Glob(pattern) => pattern.iter().try_for_each(|&c| f.write_char(c)),
+		}
+	}
+}
+
+/// Match a glob pattern, backtracking to the last `*` on a mismatch
+fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+	let (mut p, mut t) = (0, 0);
+	let mut last_star = None;
+	while t < text.len() {
+		match pattern.get(p) {
+			Some('*') => {
+				last_star = Some((p, t));
+				p += 1;
+			}
+			Some(&c) if c == '?' || c == text[t] => {
+				p += 1;
+				t += 1;
+			}
+			_ => match last_star {
+				// let the star swallow one more character and retry
+				Some((star, matched)) => {
+					last_star = Some((star, matched + 1));
+					p = star + 1;
+					t = matched + 1;
+				}
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// A blocklist or allowlist entry such as `big breasts`, `artist:name` or `*guro*`
+///
+/// Entries without a namespace match tags of any type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagRule {
+	namespace: Option<Namespace>,
+	pattern: NamePattern,
+}
+
+impl TagRule {
+	/// Parse an entry, translating localized names to English
+	pub fn parse(entry: &str) -> Self {
+		let entry = entry.trim();
+		if let Some((prefix, name)) = entry.split_once(':')
+			&& let Some(namespace) = Namespace::parse(prefix.trim())
+		{
+			return Self {
+				namespace: Some(namespace),
+				pattern: NamePattern::parse(name, Some(namespace)),
+			};
+		}
+
+		Self {
+			namespace: None,
+			pattern: NamePattern::parse(entry, None),
+		}
+	}
+
+	/// The tag to use in the search query, if nhentai can express this rule
+	pub fn query_tag(&self) -> Option<(Namespace, &str)> {
+		match &self.pattern {
+			NamePattern::Exact(name) => Some((self.namespace.unwrap_or(Namespace::Tag), name)),
+			NamePattern::Glob(_) => None,
+		}
+	}
+
+	/// Whether the search query tag covers every tag this rule matches
+	pub fn is_exact(&self) -> bool {
+		self.namespace.is_some() && matches!(self.pattern, NamePattern::Exact(_))
+	}
+
+	pub fn matches(&self, tag: &NHentaiTag) -> bool {
+		self.namespace
+			.is_none_or(|namespace| namespace.as_str() == tag.r#type)
+			&& self.pattern.matches(&tag.name)
+	}
+}
+
+impl fmt::Display for TagRule {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(namespace) = self.namespace {
+			write!(f, "{}:", namespace.as_str())?;
+		}
+		write!(f, "{}", self.pattern)
+	}
+}
+
+/// Tags a gallery must have to be shown
+#[derive(Clone)]
+pub struct Allowlist {
+	rules: Vec<TagRule>,
+	mode: AllowlistMode,
+}
+
+impl Allowlist {
+	pub fn new(rules: Vec<TagRule>, mode: AllowlistMode) -> Self {
+		Self { rules, mode }
+	}
+
+	/// Require allowlisted tags in the search query where nhentai can express it
+	///
+	/// Only possible when every rule has to match, as nhentai joins terms with AND.
+	pub fn apply(&self, query: &mut SearchQuery) {
+		if self.mode != AllowlistMode::All && self.rules.len() > 1 {
+			return;
+		}
+		for rule in self.rules.iter().filter(|rule| rule.is_exact()) {
+			if let Some((namespace, name)) = rule.query_tag() {
+				query.include(namespace, name);
+			}
+		}
+	}
+
+	pub fn allows(&self, tags: &[NHentaiTag]) -> bool {
+		if self.rules.is_empty() {
+			return true;
+		}
+		let matches = |rule: &TagRule| tags.iter().any(|tag| rule.matches(tag));
+		match self.mode {
+			AllowlistMode::Any => self.rules.iter().any(matches),
+			AllowlistMode::All => self.rules.iter().all(matches),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn glob(pattern: &str, text: &str) -> bool {
+		let pattern = pattern.chars().collect::<Vec<_>>();
+		let text = text.chars().collect::<Vec<_>>();
+		glob_matches(&pattern, &text)
+	}
+
+	#[test]
+	fn star_positions() {
+		assert!(glob("*guro", "ryona guro"));
+		assert!(!glob("*guro", "guro ryona"));
+		assert!(glob("big*breasts", "big breasts"));
+		assert!(glob("big*breasts", "bigbreasts"));
+		assert!(!glob("big*breasts", "big breast"));
+		assert!(glob("guro*", "guro"));
+		assert!(glob("guro*", "guro ryona"));
+		assert!(!glob("guro*", "ryona guro"));
+	}
+
+	#[test]
+	fn question_mark() {
+		assert!(glob("l?li", "loli"));
+		assert!(!glob("l?li", "lli"));
+		assert!(!glob("l?li", "looli"));
+	}
+
+	#[test]
+	fn repeated_stars_backtrack() {
+		assert!(glob("**", ""));
+		assert!(glob("*a*b*", "xaybz"));
+		assert!(glob("*ab", "aab"));
+		assert!(!glob("*a*b*", "xbya"));
+	}
+
+	#[test]
+	fn empty_pattern() {
+		assert!(glob("", ""));
+		assert!(!glob("", "guro"));
+		assert!(glob("*", ""));
+	}
+
+	#[test]
+	fn non_ascii_names() {
+		assert!(glob("*猎奇*", "重口猎奇"));
+		assert!(glob("ふた?り", "ふたなり"));
+		assert!(!glob("ふた?り", "ふたり"));
+	}
+
+	#[test]
+	fn patterns_ignore_case() {
+		let pattern = NamePattern::parse("*GURO*", None);
+		assert!(pattern.matches("Ryona Guro"));
+	}
+}
@@ -0,0 +1,71 @@
+use core::cell::{Cell, RefCell};
+
+/// A value loaded on first use and kept until invalidated
+pub struct Cache<T> {
+	value: RefCell<Option<T>>,
+	/// Bumped by `set` and `invalidate`, so `with` can tell whether the
+	/// value it took out was replaced in the meantime
+	generation: Cell<u32>,
+}
+
+// SAFETY: sources are compiled to wasm32 without threads, so a static cache is
+// never reached from more than one thread. `T: Send` keeps the cached value
+// itself safe to hand over should that ever change.
+unsafe impl<T: Send> Sync for Cache<T> {}
+
+impl<T> Cache<T> {
+	pub const fn new() -> Self {
+		Self {
+			value: RefCell::new(None),
+			generation: Cell::new(0),
+		}
+	}
+
+	/// Run `f` on the cached value, loading it with `load` first if needed
+	///
+	/// No borrow is held while `load` or `f` run, so either may use the cache
+	/// again without panicking.
+	pub fn with<R>(&self, load: impl FnOnce() -> T, f: impl FnOnce(&T) -> R) -> R {
+		let generation = self.generation.get();
+		let value = self.value.take().unwrap_or_else(load);
+		let result = f(&value);
+		// keep whatever `set` or `invalidate` left behind during the callbacks
+		if self.generation.get() == generation {
+			self.value.replace(Some(value));
+		}
+		result
+	}
+
+	pub fn set(&self, value: T) {
+		self.generation.set(self.generation.get().wrapping_add(1));
+		self.value.replace(Some(value));
+	}
+
+	/// Drop the cached value so the next use loads it again
+	pub fn invalidate(&self) {
+		self.generation.set(self.generation.get().wrapping_add(1));
+		self.value.replace(None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn callbacks_can_use_the_cache() {
+		let cache = Cache::new();
+		let sum = cache.with(|| 1, |&one| cache.with(|| 2, |&two| one + two));
+		assert_eq!(sum, 3);
+		assert_eq!(cache.with(|| 0, |&value| value), 1);
+	}
+
+	#[test]
+	fn set_during_callback_wins() {
+		let cache = Cache::new();
+		cache.with(|| 1, |_| cache.set(2));
+		assert_eq!(cache.with(|| 0, |&value| value), 2);
+		cache.with(|| 3, |_| cache.invalidate());
+		assert_eq!(cache.with(|| 4, |&value| value), 4);
+	}
+}
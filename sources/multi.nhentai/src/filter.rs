@@ -0,0 +1,416 @@
+use crate::{
+	blocklist::{Allowlist, TagRule},
+	models::{NHentaiGallery, NHentaiTag},
+	query::{Namespace, SearchQuery},
+	settings,
+	strings::{self, Strings},
+};
+use aidoku::{
+	Manga,
+	alloc::{String, Vec},
+	prelude::*,
+};
+
+/// Number of reasons listed in a hidden summary
+const SUMMARY_REASONS: usize = 3;
+
+/// Why a gallery is hidden from results
+#[derive(Debug, Clone, PartialEq)]
+pub enum HideReason {
+	/// Has a blocklisted tag
+	Tag(String),
+	/// Is in a blocklisted language
+	Language(String),
+	/// Has a blocked keyword in its title
+	TitleKeyword(String),
+	/// Lacks the allowlisted tags
+	NotAllowlisted,
+	/// Has fewer pages than the minimum
+	FewPages(i32),
+	/// Has fewer favorites than the minimum
+	FewFavorites(i32),
+}
+
+impl HideReason {
+	/// Short label such as `language:english` or `pages<20`
+	fn label(&self, strings: &Strings) -> String {
+		match self {
+			Self::Tag(name) => name.clone(),
+			Self::Language(name) => format!("{}:{name}", strings.reason_language),
+			Self::TitleKeyword(keyword) => format!("{}:{keyword}", strings.reason_title),
+			Self::NotAllowlisted => strings.reason_allowlist.into(),
+			Self::FewPages(min) => format!("{}<{min}", strings.reason_pages),
+			Self::FewFavorites(min) => format!("{}<{min}", strings.reason_favorites),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+	Keep,
+	Hide(HideReason),
+}
+
+/// Parse blocklist or allowlist entries, converting localized tags to English for matching
+fn parse_rules(entries: Vec<String>) -> Vec<TagRule> {
+	entries.iter().map(|entry| TagRule::parse(entry)).collect()
+}
+
+/// Minimum page and favorite counts for a gallery to be shown
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Thresholds {
+	min_pages: i32,
+	min_favorites: i32,
+}
+
+impl Thresholds {
+	pub fn from_settings() -> Self {
+		Self {
+			min_pages: settings::get_min_pages(),
+			min_favorites: settings::get_min_favorites(),
+		}
+	}
+
+	/// Add the page minimum to the search query, as nhentai can filter by page count
+	fn apply(&self, query: &mut SearchQuery) {
+		query.pages(u32::try_from(self.min_pages).ok(), None);
+	}
+
+	fn check(&self, gallery: &NHentaiGallery) -> Option<HideReason> {
+		if gallery.num_pages < self.min_pages {
+			Some(HideReason::FewPages(self.min_pages))
+		} else if gallery.num_favorites < self.min_favorites {
+			Some(HideReason::FewFavorites(self.min_favorites))
+		} else {
+			None
+		}
+	}
+}
+
+/// Tally of the galleries hidden from a page, by reason
+#[derive(Debug, Default)]
+pub struct HiddenSummary {
+	reasons: Vec<(HideReason, usize)>,
+}
+
+impl HiddenSummary {
+	fn add(&mut self, reason: HideReason) {
+		match self.reasons.iter_mut().find(|(r, _)| *r == reason) {
+			Some((_, count)) => *count += 1,
+			None => self.reasons.push((reason, 1)),
+		}
+	}
+
+	/// Describe the most common reasons, e.g. `hidden 3 (guro ×2, title:ai)`
+	pub fn describe(&self) -> Option<String> {
+		let total: usize = self.reasons.iter().map(|(_, count)| count).sum();
+		if total == 0 {
+			return None;
+		}
+
+		let strings = strings::get();
+		let mut reasons = self.reasons.iter().collect::<Vec<_>>();
+		reasons.sort_by_key(|(_, count)| core::cmp::Reverse(*count));
+		let mut parts = reasons
+			.iter()
+			.take(SUMMARY_REASONS)
+			.map(|(reason, count)| match count {
+				1 => reason.label(strings),
+				_ => format!("{} ×{count}", reason.label(strings)),
+			})
+			.collect::<Vec<_>>();
+		if reasons.len() > SUMMARY_REASONS {
+			parts.push("…".into());
+		}
+		Some(format!("{} {total} ({})", strings.hidden, parts.join(", ")))
+	}
+}
+
+/// Decides which galleries are shown in search results, listings and home
+#[derive(Clone)]
+pub struct ContentFilter {
+	blocklist: Vec<TagRule>,
+	title_blocklist: Vec<String>,
+	allowlist: Allowlist,
+	thresholds: Thresholds,
+	/// Tags the search asks for, which the blocklist doesn't hide
+	searched: Vec<(Namespace, String)>,
+	/// Keep hidden galleries with their title marked instead of removing them
+	reveal: bool,
+}
+
+impl ContentFilter {
+	pub fn new(
+		blocklist: Vec<TagRule>,
+		title_blocklist: Vec<String>,
+		allowlist: Allowlist,
+		reveal: bool,
+	) -> Self {
+		Self {
+			blocklist,
+			title_blocklist,
+			allowlist,
+			thresholds: Thresholds::default(),
+			searched: Vec::new(),
+			reveal,
+		}
+	}
+
+	pub fn from_settings() -> Self {
+		Self::new(
+			parse_rules(settings::get_blocklist()),
+			settings::get_title_blocklist(),
+			Allowlist::new(
+				parse_rules(settings::get_allowlist()),
+				settings::get_allowlist_mode(),
+			),
+			settings::get_show_hidden(),
+		)
+	}
+
+	/// Also hide galleries below the given page and favorite counts
+	pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+		self.thresholds = thresholds;
+		self
+	}
+
+	/// Move the rules nhentai can express into the search query, so filtered
+	/// pages aren't left empty
+	///
+	/// When hidden galleries are revealed the query is left as is, so they
+	/// still come back to be labeled.
+	///
+	/// Blocklisted tags the query explicitly asks for are left alone, here and
+	/// in `check`, whether or not the rule has a namespace.
+	pub fn apply(&mut self, query: &mut SearchQuery) {
+		self.searched = query
+			.included_tags()
+			.map(|(namespace, name)| (namespace, name.into()))
+			.collect();
+		// revealed galleries have to come back from nhentai to be labeled
+		if self.reveal {
+			return;
+		}
+		for rule in &self.blocklist {
+			if let Some((namespace, name)) = rule.query_tag()
+				&& !query.includes(namespace, name)
+			{
+				query.exclude(namespace, name);
+			}
+		}
+		self.blocklist.retain(|rule| !rule.is_exact());
+		self.allowlist.apply(query);
+		self.thresholds.apply(query);
+	}
+
+	fn is_searched(&self, tag: &NHentaiTag) -> bool {
+		self.searched.iter().any(|(namespace, name)| {
+			namespace.as_str() == tag.r#type && tag.name.eq_ignore_ascii_case(name)
+		})
+	}
+
+	pub fn check(&self, gallery: &NHentaiGallery) -> Verdict {
+		let blocked = gallery.tags.iter().find(|tag| {
+			!self.is_searched(tag) && self.blocklist.iter().any(|rule| rule.matches(tag))
+		});
+		if let Some(tag) = blocked {
+			let reason = match tag.r#type.as_str() {
+				"language" => HideReason::Language(tag.name.clone()),
+				_ => HideReason::Tag(tag.name.clone()),
+			};
+			return Verdict::Hide(reason);
+		}
+
+		if let Some(keyword) = gallery.title.find_keyword(&self.title_blocklist) {
+			return Verdict::Hide(HideReason::TitleKeyword(keyword.into()));
+		}
+
+		if !self.allowlist.allows(&gallery.tags) {
+			return Verdict::Hide(HideReason::NotAllowlisted);
+		}
+
+		if let Some(reason) = self.thresholds.check(gallery) {
+			return Verdict::Hide(reason);
+		}
+
+		Verdict::Keep
+	}
+
+	/// Convert galleries to entries, recording the ones the filter hides
+	pub fn entries(
+		&self,
+		galleries: Vec<NHentaiGallery>,
+		hidden: &mut HiddenSummary,
+	) -> Vec<Manga> {
+		let strings = strings::get();
+		galleries
+			.into_iter()
+			.filter_map(|gallery| match self.check(&gallery) {
+				Verdict::Keep => Some(gallery.into()),
+				Verdict::Hide(reason) => {
+					let entry = self.reveal.then(|| {
+						let mut manga = Manga::from(gallery);
+						manga.title = format!("[{}] {}", reason.label(strings), manga.title);
+						manga
+					});
+					hidden.add(reason);
+					entry
+				}
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{NHentaiImage, NHentaiImages, NHentaiTitle},
+		settings::AllowlistMode,
+	};
+	use aidoku::alloc::string::ToString;
+
+	fn gallery(
+		title: &str,
+		tags: &[(&str, &str)],
+		num_pages: i32,
+		num_favorites: i32,
+	) -> NHentaiGallery {
+		let image = NHentaiImage {
+			t: "j".into(),
+			w: 0,
+			h: 0,
+		};
+		NHentaiGallery {
+			id: 1.into(),
+			media_id: "1".into(),
+			title: NHentaiTitle {
+				english: title.into(),
+				japanese: None,
+				pretty: title.into(),
+			},
+			images: NHentaiImages {
+				pages: Vec::new(),
+				cover: image.clone(),
+				thumbnail: image,
+			},
+			tags: tags
+				.iter()
+				.map(|(r#type, name)| NHentaiTag {
+					id: 0,
+					name: (*name).into(),
+					count: 0,
+					r#type: (*r#type).into(),
+					url: String::new(),
+				})
+				.collect(),
+			num_pages,
+			num_favorites,
+			upload_date: 0,
+		}
+	}
+
+	fn rules(entries: &[&str]) -> Vec<TagRule> {
+		entries.iter().map(|entry| TagRule::parse(entry)).collect()
+	}
+
+	fn filter(blocklist: &[&str], title_blocklist: &[&str], allowlist: Allowlist) -> ContentFilter {
+		let title_blocklist = title_blocklist.iter().map(|k| (*k).into()).collect();
+		ContentFilter::new(rules(blocklist), title_blocklist, allowlist, false)
+	}
+
+	fn applied(mut filter: ContentFilter) -> String {
+		let mut query = SearchQuery::new();
+		filter.apply(&mut query);
+		query.to_string()
+	}
+
+	fn no_allowlist() -> Allowlist {
+		Allowlist::new(Vec::new(), AllowlistMode::Any)
+	}
+
+	#[test]
+	fn blocklist_hit() {
+		let filter = filter(&["guro", "language:english"], &[], no_allowlist());
+		assert_eq!(
+			filter.check(&gallery("a", &[("tag", "Guro")], 20, 0)),
+			Verdict::Hide(HideReason::Tag("Guro".into()))
+		);
+		assert_eq!(
+			filter.check(&gallery("a", &[("language", "english")], 20, 0)),
+			Verdict::Hide(HideReason::Language("english".into()))
+		);
+		// a namespaced rule ignores other namespaces
+		assert_eq!(
+			filter.check(&gallery("a", &[("tag", "english")], 20, 0)),
+			Verdict::Keep
+		);
+	}
+
+	#[test]
+	fn title_blocklist_hit() {
+		let filter = filter(&[], &["[ai generated]"], no_allowlist());
+		assert_eq!(
+			filter.check(&gallery("[AI Generated] Title", &[], 20, 0)),
+			Verdict::Hide(HideReason::TitleKeyword("[ai generated]".into()))
+		);
+		assert_eq!(filter.check(&gallery("Title", &[], 20, 0)), Verdict::Keep);
+	}
+
+	#[test]
+	fn allowlist_all_mode() {
+		let allowlist = Allowlist::new(rules(&["glasses", "artist:shindol"]), AllowlistMode::All);
+		let filter = filter(&[], &[], allowlist);
+		assert_eq!(
+			filter.check(&gallery("a", &[("tag", "glasses")], 20, 0)),
+			Verdict::Hide(HideReason::NotAllowlisted)
+		);
+		let tags = [("tag", "glasses"), ("artist", "shindol")];
+		assert_eq!(filter.check(&gallery("a", &tags, 20, 0)), Verdict::Keep);
+	}
+
+	#[test]
+	fn allowlist_single_rule() {
+		let allowlist = Allowlist::new(rules(&["glasses"]), AllowlistMode::Any);
+		let filter = filter(&[], &[], allowlist);
+		assert_eq!(
+			filter.check(&gallery("a", &[("tag", "glasses")], 20, 0)),
+			Verdict::Keep
+		);
+		assert_eq!(
+			filter.check(&gallery("a", &[("tag", "stockings")], 20, 0)),
+			Verdict::Hide(HideReason::NotAllowlisted)
+		);
+	}
+
+	#[test]
+	fn thresholds() {
+		let filter = filter(&[], &[], no_allowlist()).with_thresholds(Thresholds {
+			min_pages: 10,
+			min_favorites: 50,
+		});
+		assert_eq!(
+			filter.check(&gallery("a", &[], 9, 100)),
+			Verdict::Hide(HideReason::FewPages(10))
+		);
+		assert_eq!(
+			filter.check(&gallery("a", &[], 10, 49)),
+			Verdict::Hide(HideReason::FewFavorites(50))
+		);
+		assert_eq!(filter.check(&gallery("a", &[], 10, 50)), Verdict::Keep);
+	}
+
+	#[test]
+	fn reveal_leaves_the_query_alone() {
+		let allowlist = Allowlist::new(rules(&["tag:glasses"]), AllowlistMode::All);
+		let thresholds = Thresholds {
+			min_pages: 10,
+			min_favorites: 0,
+		};
+		let hiding = filter(&["tag:guro"], &[], allowlist.clone()).with_thresholds(thresholds);
+		assert_eq!(applied(hiding), r#"-tag:"guro" tag:"glasses" pages:>9"#);
+		let revealing = ContentFilter::new(rules(&["tag:guro"]), Vec::new(), allowlist, true)
+			.with_thresholds(thresholds);
+		assert_eq!(applied(revealing), "");
+	}
+}
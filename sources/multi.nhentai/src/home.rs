@@ -1,102 +1,87 @@
 use crate::{
-	models::NHentaiSearchResponse, normalize_blocklist, settings, NHentai, API_URL,
+	NHentai, apply_language,
+	filter::{ContentFilter, HiddenSummary, Thresholds},
+	models::{NHentaiGallery, NHentaiSearchResponse},
+	query::{SearchQuery, SortOrder},
+	settings, strings,
 };
 use aidoku::{
-	alloc::{vec, Vec},
-	helpers::uri::encode_uri_component,
+	Home, HomeComponent, HomeLayout, HomePartialResult, Listing, ListingKind, Result,
+	alloc::{Vec, vec},
 	imports::{
 		net::{Request, RequestError, Response},
 		std::send_partial_result,
 	},
-	prelude::*,
-	Home, HomeComponent, HomeLayout, HomePartialResult, Listing, ListingKind, Manga, Result,
 };
 
 impl Home for NHentai {
 	fn get_home(&self) -> Result<HomeLayout> {
+		let strings = strings::get();
+
 		// send basic home layout
 		send_partial_result(&HomePartialResult::Layout(HomeLayout {
 			components: vec![
 				HomeComponent {
-					title: Some("Popular Today".into()),
+					title: Some(strings.popular_today.into()),
 					subtitle: None,
 					value: aidoku::HomeComponentValue::empty_big_scroller(),
 				},
 				HomeComponent {
-					title: Some("Popular This Week".into()),
+					title: Some(strings.popular_week.into()),
 					subtitle: None,
 					value: aidoku::HomeComponentValue::empty_manga_list(),
 				},
 				HomeComponent {
-					title: Some("Popular All Time".into()),
+					title: Some(strings.popular_all.into()),
 					subtitle: None,
 					value: aidoku::HomeComponentValue::empty_manga_list(),
 				},
 				HomeComponent {
-					title: Some("Latest".into()),
+					title: Some(strings.latest.into()),
 					subtitle: None,
 					value: aidoku::HomeComponentValue::empty_scroller(),
 				},
 			],
 		}));
 
-		let blocklist = normalize_blocklist(settings::get_blocklist());
-		let query = encode_uri_component(
-			settings::get_language()
-				.map(|language| format!("language:{language}"))
-				.unwrap_or(" ".into()),
-		);
+		let mut query = SearchQuery::new();
+		apply_language(&mut query);
+		let mut filter = ContentFilter::from_settings();
+		let mut recent_query = query.clone();
+		let mut recent_filter = filter.clone().with_thresholds(Thresholds::from_settings());
+		filter.apply(&mut query);
+		recent_filter.apply(&mut recent_query);
 
 		let responses: [core::result::Result<Response, RequestError>; 4] = Request::send_all([
 			// popular today
-			Request::get(format!(
-				"{API_URL}/galleries/search?query={query}&page=1&sort=popular-today"
-			))?,
+			Request::get(query.search_url(1, SortOrder::PopularToday))?,
 			// popular week
-			Request::get(format!(
-				"{API_URL}/galleries/search?query={query}&page=1&sort=popular-week"
-			))?,
+			Request::get(query.search_url(1, SortOrder::PopularWeek))?,
 			// popular all
-			Request::get(format!(
-				"{API_URL}/galleries/search?query={query}&page=1&sort=popular"
-			))?,
+			Request::get(query.search_url(1, SortOrder::Popular))?,
 			// latest
-			Request::get(format!(
-				"{API_URL}/galleries/search?query={query}&page=1&sort=recent"
-			))?,
+			Request::get(recent_query.search_url(1, SortOrder::Recent))?,
 		])
 		.try_into()
 		.expect("requests vec length should be 4");
-		let results: [Result<Vec<Manga>>; 4] = responses
-			.map(|res| res?.get_json::<NHentaiSearchResponse>())
-			.map(|res| {
-				Ok(res?
-					.result
-					.into_iter()
-					.filter(|gallery| {
-						if blocklist.is_empty() {
-							return true;
-						}
-						!gallery
-							.tags
-							.iter()
-							.any(|tag| blocklist.contains(&tag.name.to_lowercase()))
-					})
-					.map(|gallery| gallery.into())
-					.collect::<Vec<Manga>>())
-			});
+		let results: [Result<Vec<NHentaiGallery>>; 4] =
+			responses.map(|res| Ok(res?.get_json::<NHentaiSearchResponse>()?.result));
 		let [popular_today, popular_week, popular_all, recent] = results;
-		let popular_today = popular_today?;
-		let popular_week = popular_week?;
-		let popular_all = popular_all?;
-		let recent = recent?;
+		let collect = |filter: &ContentFilter, galleries| {
+			let mut hidden = HiddenSummary::default();
+			(filter.entries(galleries, &mut hidden), hidden)
+		};
+		let (popular_today, popular_today_hidden) = collect(&filter, popular_today?);
+		let (popular_week, popular_week_hidden) = collect(&filter, popular_week?);
+		let (popular_all, popular_all_hidden) = collect(&filter, popular_all?);
+		let (recent, recent_hidden) = collect(&recent_filter, recent?);
 
 		let mut components = Vec::new();
 
 		if !popular_today.is_empty() {
 			components.push(HomeComponent {
-				title: Some("Popular Today".into()),
-				subtitle: None,
+				title: Some(strings.popular_today.into()),
+				subtitle: popular_today_hidden.describe(),
 				value: aidoku::HomeComponentValue::BigScroller {
 					entries: popular_today,
 					auto_scroll_interval: Some(8.0),
@@ -106,15 +91,15 @@ impl Home for NHentai {
 
 		if !popular_week.is_empty() {
 			components.push(HomeComponent {
-				title: Some("Popular This Week".into()),
-				subtitle: None,
+				title: Some(strings.popular_week.into()),
+				subtitle: popular_week_hidden.describe(),
 				value: aidoku::HomeComponentValue::MangaList {
 					ranking: true,
 					page_size: Some(3),
 					entries: popular_week.into_iter().map(|item| item.into()).collect(),
 					listing: Some(Listing {
 						id: "popular-week".into(),
-						name: "Popular This Week".into(),
+						name: strings.popular_week.into(),
 						kind: if settings::get_list_viewer() {
 							ListingKind::List
 						} else {
@@ -127,15 +112,15 @@ impl Home for NHentai {
 
 		if !popular_all.is_empty() {
 			components.push(HomeComponent {
-				title: Some("Popular All Time".into()),
-				subtitle: None,
+				title: Some(strings.popular_all.into()),
+				subtitle: popular_all_hidden.describe(),
 				value: aidoku::HomeComponentValue::MangaList {
 					ranking: true,
 					page_size: Some(3),
 					entries: popular_all.into_iter().map(|item| item.into()).collect(),
 					listing: Some(Listing {
 						id: "popular".into(),
-						name: "Popular All Time".into(),
+						name: strings.popular_all.into(),
 						kind: if settings::get_list_viewer() {
 							ListingKind::List
 						} else {
@@ -148,13 +133,13 @@ impl Home for NHentai {
 
 		if !recent.is_empty() {
 			components.push(HomeComponent {
-				title: Some("Latest".into()),
-				subtitle: None,
+				title: Some(strings.latest.into()),
+				subtitle: recent_hidden.describe(),
 				value: aidoku::HomeComponentValue::Scroller {
 					entries: recent.into_iter().map(|item| item.into()).collect(),
 					listing: Some(Listing {
 						id: "latest".into(),
-						name: "Latest".into(),
+						name: strings.latest.into(),
 						kind: if settings::get_list_viewer() {
 							ListingKind::List
 						} else {
@@ -1,42 +1,142 @@
 #![no_std]
 use aidoku::{
 	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter, FilterValue, Listing,
-	ListingProvider, Manga, MangaPageResult, MultiSelectFilter, Page, PageContent, Result, Source,
-	SortFilter, TextFilter,
-	alloc::{String, Vec, borrow::Cow, string::ToString, vec},
-	helpers::uri::encode_uri_component,
+	ListingProvider, Manga, MangaPageResult, MultiSelectFilter, NotificationHandler, Page,
+	PageContent, RangeFilter, Result, SelectFilter, SortFilter, Source, TextFilter,
+	alloc::{String, Vec, borrow::Cow, vec},
 	imports::{error::AidokuError, net::Request},
 	prelude::*,
 };
 
+mod blocklist;
+mod cache;
+mod filter;
 mod home;
 mod localization_cn;
+mod localization_ja;
+mod localization_zh_hant;
 mod models;
+mod overrides;
+mod packed;
+mod pagination;
+mod query;
 mod settings;
+mod strings;
 mod tags;
+mod transfer;
+mod translation_db;
 
+use core::cell::RefCell;
+use filter::{ContentFilter, HiddenSummary, Thresholds};
 use models::*;
+use pagination::PageMap;
+use query::{Comparison, Namespace, RangeField, SearchQuery, SortOrder};
 use tags::TAGS_EN;
 
-/// Convert blocklist tags to English for matching
-fn normalize_blocklist(blocklist: Vec<String>) -> Vec<String> {
-	blocklist
-		.into_iter()
-		.map(|tag| reverse_translate_tag(&tag).to_lowercase())
-		.collect()
+/// Restrict a query to the language selected for the source
+fn apply_language(query: &mut SearchQuery) {
+	if let Some(language) = settings::get_language() {
+		query.include(Namespace::Language, &language);
+	}
 }
 
+/// Language tags offered in the language filter
+const LANGUAGES: [&str; 7] = [
+	"english",
+	"japanese",
+	"chinese",
+	"translated",
+	"rewrite",
+	"speechless",
+	"text cleaned",
+];
+
+/// Language tags describing how a gallery was translated rather than its language
+const LANGUAGE_MARKERS: [&str; 2] = ["translated", "rewrite"];
+
+/// Maximum gallery age as a number and nhentai time unit, e.g. `(7, 'd')`
+type UploadAge = (u32, char);
+
+/// Presets for the upload date filter as (id, age)
+const UPLOAD_AGES: [(&str, Option<UploadAge>); 5] = [
+	("any", None),
+	("day", Some((1, 'd'))),
+	("week", Some((7, 'd'))),
+	("month", Some((1, 'm'))),
+	("year", Some((1, 'y'))),
+];
+
 const BASE_URL: &str = "https://nhentai.net";
 const API_URL: &str = "https://nhentai.net/api";
 const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) \
 						  AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 \
 						  Mobile/15E148 Safari/604";
 
-struct NHentai;
+/// Number of entries a filtered search page is backfilled to
+const PAGE_SIZE: usize = 25;
+/// Maximum number of nhentai pages fetched for a single search page
+const MAX_FETCHES_PER_PAGE: i32 = 4;
+
+struct NHentai {
+	pages: RefCell<PageMap>,
+}
+
+impl NHentai {
+	/// Run a search, dropping galleries hidden by the content filter
+	///
+	/// When filtering empties most of a page, following nhentai pages are
+	/// fetched until it is filled up again.
+	fn search(
+		&self,
+		mut query: SearchQuery,
+		mut filter: ContentFilter,
+		sort: SortOrder,
+		page: i32,
+	) -> Result<MangaPageResult> {
+		filter.apply(&mut query);
+
+		let key = query.search_url(1, sort);
+		let mut next_page = self.pages.borrow().start(&key, page);
+		let fetch = |page| -> Result<NHentaiSearchResponse> {
+			Request::get(query.search_url(page, sort))?
+				.header("User-Agent", USER_AGENT)
+				.json_owned()
+		};
+		let mut entries = Vec::new();
+		let mut fetched = 0;
+		let has_next_page = loop {
+			let response = match fetch(next_page) {
+				Ok(response) => response,
+				// keep what the earlier pages found and retry this one next time
+				Err(_) if fetched > 0 => break true,
+				Err(err) => return Err(err),
+			};
+			next_page += 1;
+			fetched += 1;
+
+			entries.extend(filter.entries(response.result, &mut HiddenSummary::default()));
+
+			if next_page > response.num_pages {
+				break false;
+			}
+			if entries.len() >= PAGE_SIZE || fetched >= MAX_FETCHES_PER_PAGE {
+				break true;
+			}
+		};
+		self.pages.borrow_mut().record(&key, page + 1, next_page);
+
+		Ok(MangaPageResult {
+			entries,
+			has_next_page,
+		})
+	}
+}
 
 impl Source for NHentai {
 	fn new() -> Self {
-		Self
+		Self {
+			pages: RefCell::new(PageMap::default()),
+		}
 	}
 
 	fn get_search_manga_list(
@@ -59,104 +159,140 @@ impl Source for NHentai {
 			});
 		}
 
-		let mut query_parts = Vec::new();
+		let mut search = SearchQuery::new();
 
 		if let Some(q) = query {
-			query_parts.push(q);
+			search.push_raw(&q);
 		}
 
-		let mut sort = "recent";
+		let mut sort = SortOrder::Recent;
+		let mut uploaded = None;
+		let mut source_language = true;
 
 		// parse filters
 		for filter in filters {
 			match filter {
 				FilterValue::Text { id, value } => match id.as_str() {
 					"author" => {
-						query_parts.push(value);
+						// authors are shown localized, so map them back before searching
+						if let Some(artist) = reverse_lookup(&value, Namespace::Artist) {
+							search.include(Namespace::Artist, &artist);
+						} else if let Some(group) = reverse_lookup(&value, Namespace::Group) {
+							search.include(Namespace::Group, &group);
+						} else {
+							search.push_raw(&value);
+						}
 					}
 					"artist" => {
-						query_parts.push(format!("artist:{value}"));
+						search.include(
+							Namespace::Artist,
+							&reverse_translate_name(&value, Namespace::Artist),
+						);
 					}
 					"groups" => {
-						query_parts.push(format!("group:{value}"));
+						search.include(
+							Namespace::Group,
+							&reverse_translate_name(&value, Namespace::Group),
+						);
+					}
+					"parody" => {
+						search.include(
+							Namespace::Parody,
+							&reverse_translate_name(&value, Namespace::Parody),
+						);
+					}
+					"character" => {
+						search.include(
+							Namespace::Character,
+							&reverse_translate_name(&value, Namespace::Character),
+						);
+					}
+					"uploaded_days" => {
+						if let Ok(days) = value.trim().parse::<u32>()
+							&& days > 0
+						{
+							uploaded = Some((days, 'd'));
+						}
 					}
 					_ => continue,
 				},
 				FilterValue::Sort { index, .. } => {
-					sort = match index {
-						0 => "recent",        // Latest
-						1 => "popular-today", // Popular Today
-						2 => "popular-week",  // Popular Week
-						3 => "popular",       // Popular All
-						_ => "recent",
-					};
+					sort = SortOrder::from_index(index);
 				}
 				FilterValue::MultiSelect {
 					id,
 					included,
 					excluded,
 					..
-				} => {
-					if id == "tags" || id == "favorite_tags" {
+				} => match id.as_str() {
+					"tags" | "favorite_tags" => {
 						for tag in included {
-							let eng_tag = reverse_translate_tag(&tag);
-							query_parts.push(format!("tag:\"{eng_tag}\""));
+							search.include(Namespace::Tag, &reverse_translate_tag(&tag));
 						}
 						for tag in excluded {
-							let eng_tag = reverse_translate_tag(&tag);
-							query_parts.push(format!("-tag:\"{eng_tag}\""));
+							search.exclude(Namespace::Tag, &reverse_translate_tag(&tag));
 						}
 					}
-				}
-				FilterValue::Select { id, value } => {
-					if id == "genre" {
-						let eng_value = reverse_translate_tag(&value);
-						query_parts.push(format!("tag:\"{eng_value}\""));
+					"categories" => {
+						let categories = CATEGORIES.iter().copied();
+						search.include_any(Namespace::Category, &included, categories);
+						for category in excluded {
+							search.exclude(Namespace::Category, &category);
+						}
+					}
+					"languages" => {
+						let (markers, languages): (Vec<_>, Vec<_>) = included
+							.into_iter()
+							.partition(|language| LANGUAGE_MARKERS.contains(&language.as_str()));
+						let known = LANGUAGES
+							.iter()
+							.copied()
+							.filter(|name| !LANGUAGE_MARKERS.contains(name));
+						search.include_any(Namespace::Language, &languages, known);
+						for marker in markers {
+							search.include(Namespace::Language, &marker);
+						}
+						for language in excluded {
+							search.exclude(Namespace::Language, &language);
+						}
+						// languages picked here override the source language
+						source_language &= languages.is_empty();
+					}
+					_ => continue,
+				},
+				FilterValue::Select { id, value } => match id.as_str() {
+					"genre" => {
+						search.include(Namespace::Tag, &reverse_translate_tag(&value));
+					}
+					"uploaded" => {
+						let age = UPLOAD_AGES.iter().find(|(key, _)| *key == value);
+						if let Some((_, Some(age))) = age
+							&& uploaded.is_none()
+						{
+							uploaded = Some(*age);
+						}
+					}
+					_ => continue,
+				},
+				FilterValue::Range { id, from, to } => {
+					if id == "pages" {
+						search.pages(from.map(|min| min as u32), to.map(|max| max as u32));
 					}
 				}
 				_ => continue,
 			}
 		}
 
-		if let Some(language) = settings::get_language() {
-			query_parts.push(format!("language:{language}"));
+		// a custom number of days takes precedence over the preset
+		if let Some((value, unit)) = uploaded {
+			search.range(RangeField::Uploaded, Comparison::Less, value, Some(unit));
 		}
 
-		let combined_query = if query_parts.is_empty() {
-			" ".into()
-		} else {
-			query_parts.join(" ")
-		};
-		let url = format!(
-			"{API_URL}/galleries/search?query={}&page={page}&sort={sort}",
-			encode_uri_component(combined_query),
-		);
-		let response: NHentaiSearchResponse = Request::get(&url)?
-			.header("User-Agent", USER_AGENT)
-			.json_owned()?;
-
-		let blocklist = normalize_blocklist(settings::get_blocklist());
-
-		let entries = response
-			.result
-			.into_iter()
-			.filter(|gallery| {
-				if blocklist.is_empty() {
-					return true;
-				}
-				!gallery
-					.tags
-					.iter()
-					.any(|tag| blocklist.contains(&tag.name.to_lowercase()))
-			})
-			.map(|gallery| gallery.into())
-			.collect::<Vec<Manga>>();
-		let has_next_page = page < response.num_pages;
+		if source_language {
+			apply_language(&mut search);
+		}
 
-		Ok(MangaPageResult {
-			entries,
-			has_next_page,
-		})
+		self.search(search, ContentFilter::from_settings(), sort, page)
 	}
 
 	fn get_manga_update(
@@ -232,44 +368,17 @@ impl Source for NHentai {
 
 impl ListingProvider for NHentai {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
-		match listing.id.as_str() {
-			"popular-today" => self.get_search_manga_list(
-				None,
-				page,
-				vec![FilterValue::Sort {
-					id: "sort".to_string(),
-					index: 1,
-					ascending: false,
-				}],
-			),
-			"popular-week" => self.get_search_manga_list(
-				None,
-				page,
-				vec![FilterValue::Sort {
-					id: "sort".to_string(),
-					index: 2,
-					ascending: false,
-				}],
-			),
-			"popular" => self.get_search_manga_list(
-				None,
-				page,
-				vec![FilterValue::Sort {
-					id: "sort".to_string(),
-					index: 3,
-					ascending: false,
-				}],
-			),
-			"latest" => self.get_search_manga_list(
-				None,
-				page,
-				vec![FilterValue::Sort {
-					id: "sort".to_string(),
-					index: 0,
-					ascending: false,
-				}],
-			),
-			_ => Err(AidokuError::Unimplemented),
+		match SortOrder::from_listing(&listing.id) {
+			Some(sort) => {
+				let mut query = SearchQuery::new();
+				apply_language(&mut query);
+				let mut filter = ContentFilter::from_settings();
+				if sort == SortOrder::Recent {
+					filter = filter.with_thresholds(Thresholds::from_settings());
+				}
+				self.search(query, filter, sort, page)
+			}
+			None => Err(AidokuError::Unimplemented),
 		}
 	}
 }
@@ -300,54 +409,59 @@ impl DynamicFilters for NHentai {
 	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
 		let favorite_tags = settings::get_favorite_tags();
 		let tag_lang = settings::get_tag_language();
+		let tag_display = settings::get_tag_display();
+		let strings = strings::get();
 
-		let mut filters: Vec<Filter> = Vec::new();
-
-		// Artist filter
-		filters.push(
+		let mut filters: Vec<Filter> = vec![
+			// Artist filter
 			TextFilter {
 				id: Cow::Borrowed("artist"),
-				title: Some(Cow::Borrowed("Artist")),
-				placeholder: Some(Cow::Borrowed("Artist name")),
+				title: Some(Cow::Borrowed(strings.artist)),
+				placeholder: Some(Cow::Borrowed(strings.artist_placeholder)),
 				..Default::default()
 			}
 			.into(),
-		);
-
-		// Group filter
-		filters.push(
+			// Group filter
 			TextFilter {
 				id: Cow::Borrowed("groups"),
-				title: Some(Cow::Borrowed("Group")),
-				placeholder: Some(Cow::Borrowed("Group name")),
+				title: Some(Cow::Borrowed(strings.group)),
+				placeholder: Some(Cow::Borrowed(strings.group_placeholder)),
 				..Default::default()
 			}
 			.into(),
-		);
-
-		// Sort filter
-		filters.push(
+			// Parody filter
+			TextFilter {
+				id: Cow::Borrowed("parody"),
+				title: Some(Cow::Borrowed(strings.parody)),
+				placeholder: Some(Cow::Borrowed(strings.parody_placeholder)),
+				..Default::default()
+			}
+			.into(),
+			// Character filter
+			TextFilter {
+				id: Cow::Borrowed("character"),
+				title: Some(Cow::Borrowed(strings.character)),
+				placeholder: Some(Cow::Borrowed(strings.character_placeholder)),
+				..Default::default()
+			}
+			.into(),
+			// Sort filter
 			SortFilter {
 				id: Cow::Borrowed("sort"),
-				title: Some(Cow::Borrowed("Sort")),
+				title: Some(Cow::Borrowed(strings.sort)),
 				can_ascend: false,
-				options: vec![
-					Cow::Borrowed("Latest"),
-					Cow::Borrowed("Popular Today"),
-					Cow::Borrowed("Popular Week"),
-					Cow::Borrowed("Popular All"),
-				],
+				options: strings.sort_options.map(Cow::Borrowed).to_vec(),
 				..Default::default()
 			}
 			.into(),
-		);
+		];
 
 		// Favorite tags filter (only show if user has favorite tags)
 		if !favorite_tags.is_empty() {
 			let translated_fav_tags: Vec<Cow<'static, str>> = if tag_lang != "english" {
 				favorite_tags
 					.iter()
-					.map(|tag| Cow::Owned(translate_tag(tag, &tag_lang)))
+					.map(|tag| Cow::Owned(translate_tag(tag, &tag_lang, tag_display)))
 					.collect()
 			} else {
 				favorite_tags.into_iter().map(Cow::Owned).collect()
@@ -356,7 +470,7 @@ impl DynamicFilters for NHentai {
 			filters.push(
 				MultiSelectFilter {
 					id: Cow::Borrowed("favorite_tags"),
-					title: Some(Cow::Borrowed("Favorite Tags")),
+					title: Some(Cow::Borrowed(strings.favorite_tags)),
 					is_genre: true,
 					can_exclude: true,
 					uses_tag_style: true,
@@ -373,7 +487,7 @@ impl DynamicFilters for NHentai {
 		let mut all_tags: Vec<Cow<'static, str>> = if tag_lang != "english" {
 			TAGS_EN
 				.iter()
-				.map(|tag| Cow::Owned(translate_tag(tag, &tag_lang)))
+				.map(|tag| Cow::Owned(translate_tag(tag, &tag_lang, tag_display)))
 				.collect()
 		} else {
 			TAGS_EN.iter().map(|&s| Cow::Borrowed(s)).collect()
@@ -387,7 +501,7 @@ impl DynamicFilters for NHentai {
 		filters.push(
 			MultiSelectFilter {
 				id: Cow::Borrowed("tags"),
-				title: Some(Cow::Borrowed("Tags")),
+				title: Some(Cow::Borrowed(strings.tags)),
 				is_genre: true,
 				can_exclude: true,
 				uses_tag_style: true,
@@ -397,8 +511,87 @@ impl DynamicFilters for NHentai {
 			.into(),
 		);
 
+		// Language filter
+		filters.push(
+			MultiSelectFilter {
+				id: Cow::Borrowed("languages"),
+				title: Some(Cow::Borrowed(strings.languages)),
+				can_exclude: true,
+				options: strings.language_options.map(Cow::Borrowed).to_vec(),
+				ids: Some(LANGUAGES.map(Cow::Borrowed).to_vec()),
+				..Default::default()
+			}
+			.into(),
+		);
+
+		// Category filter
+		filters.push(
+			MultiSelectFilter {
+				id: Cow::Borrowed("categories"),
+				title: Some(Cow::Borrowed(strings.categories)),
+				can_exclude: true,
+				options: strings.category_options.map(Cow::Borrowed).to_vec(),
+				ids: Some(CATEGORIES.map(Cow::Borrowed).to_vec()),
+				..Default::default()
+			}
+			.into(),
+		);
+
+		// Page count filter
+		filters.push(
+			RangeFilter {
+				id: Cow::Borrowed("pages"),
+				title: Some(Cow::Borrowed(strings.pages)),
+				min: Some(1.0),
+				max: None,
+				decimal: false,
+				..Default::default()
+			}
+			.into(),
+		);
+
+		// Upload date filter
+		filters.push(
+			SelectFilter {
+				id: Cow::Borrowed("uploaded"),
+				title: Some(Cow::Borrowed(strings.uploaded)),
+				options: strings.upload_age_options.map(Cow::Borrowed).to_vec(),
+				ids: Some(UPLOAD_AGES.map(|(id, _)| Cow::Borrowed(id)).to_vec()),
+				..Default::default()
+			}
+			.into(),
+		);
+		filters.push(
+			TextFilter {
+				id: Cow::Borrowed("uploaded_days"),
+				title: Some(Cow::Borrowed(strings.uploaded_days)),
+				placeholder: Some(Cow::Borrowed(strings.uploaded_days_placeholder)),
+				..Default::default()
+			}
+			.into(),
+		);
+
 		Ok(filters)
 	}
 }
 
-register_source!(NHentai, Home, ListingProvider, DeepLinkHandler, DynamicFilters);
+impl NotificationHandler for NHentai {
+	fn handle_notification(&self, notification: String) {
+		match notification.as_str() {
+			"importLists" => transfer::import(),
+			"exportLists" => transfer::export(),
+			"updateTranslationDb" => translation_db::update(),
+			"translationOverrides" => overrides::invalidate(),
+			_ => {}
+		}
+	}
+}
+
+register_source!(
+	NHentai,
+	Home,
+	ListingProvider,
+	DeepLinkHandler,
+	DynamicFilters,
+	NotificationHandler
+);
@@ -0,0 +1,27 @@
+// This file is auto-generated by generate_localization_ja.py
+// Do not edit manually!
+
+use crate::packed::{Pack, Table};
+
+#[cfg(feature = "ja-tags")]
+static TAGS: Pack = Pack::new(include_bytes!("localization/ja_tags.bin"));
+#[cfg(not(feature = "ja-tags"))]
+static TAGS: Pack = Pack::new(&[]);
+
+pub static JA_TAG: Table = Table::new(&TAGS, 0);
+pub static JA_PARODY: Table = Table::new(&TAGS, 1);
+pub static JA_TAG_REVERSE: Table = Table::new(&TAGS, 2);
+pub static JA_PARODY_REVERSE: Table = Table::new(&TAGS, 3);
+
+// the names in the cn pack written in Japanese script
+#[cfg(feature = "ja-names")]
+use crate::localization_cn::NAMES;
+#[cfg(not(feature = "ja-names"))]
+static NAMES: Pack = Pack::new(&[]);
+
+pub static JA_CHARACTER: Table = Table::japanese(&NAMES, 0);
+pub static JA_GROUP: Table = Table::japanese(&NAMES, 1);
+pub static JA_ARTIST: Table = Table::japanese(&NAMES, 2);
+pub static JA_CHARACTER_REVERSE: Table = Table::japanese(&NAMES, 3);
+pub static JA_GROUP_REVERSE: Table = Table::japanese(&NAMES, 4);
+pub static JA_ARTIST_REVERSE: Table = Table::japanese(&NAMES, 5);
@@ -0,0 +1,2326 @@
+// This file is auto-generated by generate_zh_hant.py
+// Do not edit manually!
+
+// Simplified -> Traditional, for display
+pub static ZH_HANT: phf::Map<char, char> = phf::phf_map! {
+    '专' => '專',
+    '业' => '業',
+    '丛' => '叢',
+    '东' => '東',
+    '丝' => '絲',
+    '丢' => '丟',
+    '两' => '兩',
+    '严' => '嚴',
+    '丧' => '喪',
+    '个' => '個',
+    '丰' => '豐',
+    '临' => '臨',
+    '为' => '為',
+    '丽' => '麗',
+    '举' => '舉',
+    '么' => '麼',
+    '义' => '義',
+    '乌' => '烏',
+    '乐' => '樂',
+    '乔' => '喬',
+    '习' => '習',
+    '乡' => '鄉',
+    '书' => '書',
+    '买' => '買',
+    '乱' => '亂',
+    '争' => '爭',
+    '于' => '於',
+    '亏' => '虧',
+    '云' => '雲',
+    '亚' => '亞',
+    '产' => '產',
+    '亩' => '畝',
+    '亲' => '親',
+    '亵' => '褻',
+    '亿' => '億',
+    '仅' => '僅',
+    '从' => '從',
+    '仑' => '侖',
+    '仓' => '倉',
+    '仪' => '儀',
+    '们' => '們',
+    '价' => '價',
+    '众' => '眾',
+    '优' => '優',
+    '会' => '會',
+    '伞' => '傘',
+    '伟' => '偉',
+    '传' => '傳',
+    '伤' => '傷',
+    '伦' => '倫',
+    '伪' => '偽',
+    '体' => '體',
+    '佣' => '傭',
+    '侠' => '俠',
+    '侣' => '侶',
+    '侥' => '僥',
+    '侦' => '偵',
+    '侧' => '側',
+    '侨' => '僑',
+    '侬' => '儂',
+    '俦' => '儔',
+    '俩' => '倆',
+    '俪' => '儷',
+    '俭' => '儉',
+    '债' => '債',
+    '倾' => '傾',
+    '偿' => '償',
+    '储' => '儲',
+    '傩' => '儺',
+    '儿' => '兒',
+    '兑' => '兌',
+    '兰' => '蘭',
+    '关' => '關',
+    '兴' => '興',
+    '兹' => '茲',
+    '养' => '養',
+    '兽' => '獸',
+    '内' => '內',
+    '冈' => '岡',
+    '册' => '冊',
+    '写' => '寫',
+    '军' => '軍',
+    '农' => '農',
+    '冯' => '馮',
+    '冲' => '衝',
+    '决' => '決',
+    '况' => '況',
+    '冻' => '凍',
+    '净' => '淨',
+    '准' => '準',
+    '凉' => '涼',
+    '减' => '減',
+    '凑' => '湊',
+    '凛' => '凜',
+    '几' => '幾',
+    '凤' => '鳳',
+    '凭' => '憑',
+    '凯' => '凱',
+    '击' => '擊',
+    '凿' => '鑿',
+    '刍' => '芻',
+    '划' => '劃',
+    '刘' => '劉',
+    '则' => '則',
+    '刚' => '剛',
+    '创' => '創',
+    '删' => '刪',
+    '别' => '別',
+    '刹' => '剎',
+    '刽' => '劊',
+    '剂' => '劑',
+    '剐' => '剮',
+    '剑' => '劍',
+    '剥' => '剝',
+    '剧' => '劇',
+    '劝' => '勸',
+    '办' => '辦',
+    '务' => '務',
+    '动' => '動',
+    '励' => '勵',
+    '劲' => '勁',
+    '劳' => '勞',
+    '势' => '勢',
+    '勋' => '勳',
+    '匀' => '勻',
+    '区' => '區',
+    '医' => '醫',
+    '华' => '華',
+    '协' => '協',
+    '单' => '單',
+    '卖' => '賣',
+    '卢' => '盧',
+    '卤' => '滷',
+    '卫' => '衛',
+    '却' => '卻',
+    '厂' => '廠',
+    '厅' => '廳',
+    '历' => '歷',
+    '厉' => '厲',
+    '压' => '壓',
+    '厌' => '厭',
+    '厕' => '廁',
+    '厢' => '廂',
+    '厦' => '廈',
+    '厨' => '廚',
+    '县' => '縣',
+    '叁' => '叄',
+    '参' => '參',
+    '双' => '雙',
+    '发' => '發',
+    '变' => '變',
+    '叙' => '敘',
+    '叠' => '疊',
+    '叶' => '葉',
+    '号' => '號',
+    '叹' => '嘆',
+    '叽' => '嘰',
+    '吓' => '嚇',
+    '吕' => '呂',
+    '吗' => '嗎',
+    '吨' => '噸',
+    '听' => '聽',
+    '启' => '啟',
+    '吴' => '吳',
+    '呐' => '吶',
+    '呕' => '嘔',
+    '呗' => '唄',
+    '员' => '員',
+    '呛' => '嗆',
+    '呜' => '嗚',
+    '咏' => '詠',
+    '咙' => '嚨',
+    '响' => '響',
+    '哑' => '啞',
+    '哒' => '噠',
+    '哔' => '嗶',
+    '哗' => '嘩',
+    '哟' => '喲',
+    '唤' => '喚',
+    '啮' => '嚙',
+    '啰' => '囉',
+    '啸' => '嘯',
+    '喷' => '噴',
+    '嘘' => '噓',
+    '嘱' => '囑',
+    '噜' => '嚕',
+    '嚣' => '囂',
+    '团' => '團',
+    '园' => '園',
+    '围' => '圍',
+    '国' => '國',
+    '图' => '圖',
+    '圆' => '圓',
+    '圣' => '聖',
+    '场' => '場',
+    '坏' => '壞',
+    '块' => '塊',
+    '坚' => '堅',
+    '坛' => '壇',
+    '坝' => '壩',
+    '坞' => '塢',
+    '坟' => '墳',
+    '坠' => '墜',
+    '垄' => '壟',
+    '垒' => '壘',
+    '垦' => '墾',
+    '垭' => '埡',
+    '堕' => '墮',
+    '墙' => '牆',
+    '壮' => '壯',
+    '声' => '聲',
+    '壳' => '殼',
+    '壶' => '壺',
+    '处' => '處',
+    '备' => '備',
+    '复' => '復',
+    '够' => '夠',
+    '头' => '頭',
+    '夸' => '誇',
+    '夹' => '夾',
+    '夺' => '奪',
+    '奋' => '奮',
+    '奖' => '獎',
+    '妆' => '妝',
+    '妇' => '婦',
+    '妈' => '媽',
+    '姗' => '姍',
+    '娄' => '婁',
+    '娅' => '婭',
+    '娇' => '嬌',
+    '娱' => '娛',
+    '婴' => '嬰',
+    '婶' => '嬸',
+    '孙' => '孫',
+    '学' => '學',
+    '孪' => '孿',
+    '宁' => '寧',
+    '宝' => '寶',
+    '实' => '實',
+    '宠' => '寵',
+    '审' => '審',
+    '宪' => '憲',
+    '宫' => '宮',
+    '宽' => '寬',
+    '宾' => '賓',
+    '寝' => '寢',
+    '对' => '對',
+    '寻' => '尋',
+    '导' => '導',
+    '寿' => '壽',
+    '将' => '將',
+    '尔' => '爾',
+    '尘' => '塵',
+    '尝' => '嘗',
+    '尧' => '堯',
+    '尴' => '尷',
+    '尸' => '屍',
+    '尽' => '盡',
+    '层' => '層',
+    '屉' => '屜',
+    '届' => '屆',
+    '属' => '屬',
+    '屡' => '屢',
+    '屿' => '嶼',
+    '岁' => '歲',
+    '岂' => '豈',
+    '岗' => '崗',
+    '岚' => '嵐',
+    '岛' => '島',
+    '岭' => '嶺',
+    '峡' => '峽',
+    '峦' => '巒',
+    '崭' => '嶄',
+    '巅' => '巔',
+    '巩' => '鞏',
+    '币' => '幣',
+    '帅' => '帥',
+    '师' => '師',
+    '帐' => '帳',
+    '帜' => '幟',
+    '带' => '帶',
+    '帮' => '幫',
+    '并' => '並',
+    '广' => '廣',
+    '庄' => '莊',
+    '庆' => '慶',
+    '库' => '庫',
+    '应' => '應',
+    '庙' => '廟',
+    '庞' => '龐',
+    '废' => '廢',
+    '开' => '開',
+    '异' => '異',
+    '弃' => '棄',
+    '弑' => '弒',
+    '张' => '張',
+    '弥' => '彌',
+    '弯' => '彎',
+    '弹' => '彈',
+    '强' => '強',
+    '归' => '歸',
+    '当' => '當',
+    '录' => '錄',
+    '彦' => '彥',
+    '彻' => '徹',
+    '径' => '徑',
+    '忆' => '憶',
+    '忏' => '懺',
+    '忧' => '憂',
+    '怀' => '懷',
+    '态' => '態',
+    '怂' => '慫',
+    '怜' => '憐',
+    '总' => '總',
+    '恋' => '戀',
+    '恒' => '恆',
+    '恳' => '懇',
+    '恶' => '惡',
+    '恼' => '惱',
+    '悦' => '悅',
+    '悬' => '懸',
+    '悯' => '憫',
+    '惊' => '驚',
+    '惧' => '懼',
+    '惨' => '慘',
+    '惩' => '懲',
+    '惫' => '憊',
+    '惭' => '慚',
+    '惯' => '慣',
+    '愤' => '憤',
+    '愿' => '願',
+    '慑' => '懾',
+    '懒' => '懶',
+    '戏' => '戲',
+    '战' => '戰',
+    '户' => '戶',
+    '执' => '執',
+    '扩' => '擴',
+    '扫' => '掃',
+    '扬' => '揚',
+    '扰' => '擾',
+    '抚' => '撫',
+    '抛' => '拋',
+    '抢' => '搶',
+    '护' => '護',
+    '报' => '報',
+    '担' => '擔',
+    '拟' => '擬',
+    '拢' => '攏',
+    '拥' => '擁',
+    '拦' => '攔',
+    '拧' => '擰',
+    '拨' => '撥',
+    '择' => '擇',
+    '挂' => '掛',
+    '挚' => '摯',
+    '挞' => '撻',
+    '挟' => '挾',
+    '挠' => '撓',
+    '挡' => '擋',
+    '挣' => '掙',
+    '挤' => '擠',
+    '挥' => '揮',
+    '捞' => '撈',
+    '损' => '損',
+    '捡' => '撿',
+    '换' => '換',
+    '捣' => '搗',
+    '据' => '據',
+    '掳' => '擄',
+    '掷' => '擲',
+    '掸' => '撣',
+    '揽' => '攬',
+    '搀' => '攙',
+    '携' => '攜',
+    '摄' => '攝',
+    '摆' => '擺',
+    '摇' => '搖',
+    '摊' => '攤',
+    '撑' => '撐',
+    '撵' => '攆',
+    '敌' => '敵',
+    '数' => '數',
+    '斋' => '齋',
+    '斓' => '斕',
+    '斩' => '斬',
+    '断' => '斷',
+    '无' => '無',
+    '旧' => '舊',
+    '时' => '時',
+    '旷' => '曠',
+    '昙' => '曇',
+    '昼' => '晝',
+    '显' => '顯',
+    '晋' => '晉',
+    '晒' => '曬',
+    '晓' => '曉',
+    '晕' => '暈',
+    '晖' => '暉',
+    '暂' => '暫',
+    '术' => '術',
+    '机' => '機',
+    '杀' => '殺',
+    '杂' => '雜',
+    '权' => '權',
+    '条' => '條',
+    '来' => '來',
+    '杨' => '楊',
+    '杰' => '傑',
+    '极' => '極',
+    '构' => '構',
+    '枢' => '樞',
+    '枣' => '棗',
+    '枨' => '棖',
+    '枪' => '槍',
+    '枫' => '楓',
+    '枭' => '梟',
+    '柜' => '櫃',
+    '柠' => '檸',
+    '栀' => '梔',
+    '标' => '標',
+    '栈' => '棧',
+    '栉' => '櫛',
+    '栋' => '棟',
+    '栎' => '櫟',
+    '栏' => '欄',
+    '树' => '樹',
+    '样' => '樣',
+    '档' => '檔',
+    '桥' => '橋',
+    '桦' => '樺',
+    '桧' => '檜',
+    '梦' => '夢',
+    '检' => '檢',
+    '椭' => '橢',
+    '楼' => '樓',
+    '榄' => '欖',
+    '榈' => '櫚',
+    '槛' => '檻',
+    '槟' => '檳',
+    '横' => '橫',
+    '樱' => '櫻',
+    '橹' => '櫓',
+    '欢' => '歡',
+    '欧' => '歐',
+    '歼' => '殲',
+    '残' => '殘',
+    '殴' => '毆',
+    '毁' => '毀',
+    '毕' => '畢',
+    '毙' => '斃',
+    '气' => '氣',
+    '汇' => '匯',
+    '汉' => '漢',
+    '汤' => '湯',
+    '沟' => '溝',
+    '没' => '沒',
+    '沦' => '淪',
+    '沧' => '滄',
+    '沪' => '滬',
+    '泞' => '濘',
+    '泪' => '淚',
+    '泷' => '瀧',
+    '泻' => '瀉',
+    '泼' => '潑',
+    '泽' => '澤',
+    '洁' => '潔',
+    '洒' => '灑',
+    '浅' => '淺',
+    '浆' => '漿',
+    '浇' => '澆',
+    '浊' => '濁',
+    '测' => '測',
+    '济' => '濟',
+    '浏' => '瀏',
+    '浑' => '渾',
+    '浒' => '滸',
+    '浓' => '濃',
+    '涂' => '塗',
+    '涛' => '濤',
+    '涟' => '漣',
+    '涡' => '渦',
+    '涤' => '滌',
+    '润' => '潤',
+    '涨' => '漲',
+    '涩' => '澀',
+    '渊' => '淵',
+    '渐' => '漸',
+    '渔' => '漁',
+    '温' => '溫',
+    '湾' => '灣',
+    '湿' => '濕',
+    '溃' => '潰',
+    '溅' => '濺',
+    '滚' => '滾',
+    '满' => '滿',
+    '滤' => '濾',
+    '滥' => '濫',
+    '滨' => '濱',
+    '滩' => '灘',
+    '潇' => '瀟',
+    '潜' => '潛',
+    '灭' => '滅',
+    '灯' => '燈',
+    '灵' => '靈',
+    '灾' => '災',
+    '灿' => '燦',
+    '炉' => '爐',
+    '点' => '點',
+    '炼' => '煉',
+    '炽' => '熾',
+    '烁' => '爍',
+    '烂' => '爛',
+    '烛' => '燭',
+    '烟' => '煙',
+    '烦' => '煩',
+    '烧' => '燒',
+    '烨' => '燁',
+    '热' => '熱',
+    '焕' => '煥',
+    '爱' => '愛',
+    '爷' => '爺',
+    '牵' => '牽',
+    '状' => '狀',
+    '犹' => '猶',
+    '狈' => '狽',
+    '独' => '獨',
+    '狭' => '狹',
+    '狮' => '獅',
+    '狱' => '獄',
+    '猃' => '獫',
+    '猎' => '獵',
+    '猪' => '豬',
+    '猫' => '貓',
+    '猬' => '蝟',
+    '献' => '獻',
+    '獭' => '獺',
+    '玛' => '瑪',
+    '环' => '環',
+    '现' => '現',
+    '玺' => '璽',
+    '珐' => '琺',
+    '琐' => '瑣',
+    '琼' => '瓊',
+    '瑶' => '瑤',
+    '璎' => '瓔',
+    '电' => '電',
+    '画' => '畫',
+    '畅' => '暢',
+    '疗' => '療',
+    '疮' => '瘡',
+    '疯' => '瘋',
+    '痒' => '癢',
+    '痴' => '癡',
+    '瘫' => '癱',
+    '盏' => '盞',
+    '盐' => '鹽',
+    '监' => '監',
+    '盖' => '蓋',
+    '盗' => '盜',
+    '盘' => '盤',
+    '着' => '著',
+    '睁' => '睜',
+    '矫' => '矯',
+    '矶' => '磯',
+    '码' => '碼',
+    '砖' => '磚',
+    '砚' => '硯',
+    '砾' => '礫',
+    '础' => '礎',
+    '硕' => '碩',
+    '确' => '確',
+    '碍' => '礙',
+    '礼' => '禮',
+    '祢' => '禰',
+    '祷' => '禱',
+    '祸' => '禍',
+    '禄' => '祿',
+    '禅' => '禪',
+    '离' => '離',
+    '秃' => '禿',
+    '种' => '種',
+    '积' => '積',
+    '称' => '稱',
+    '秽' => '穢',
+    '稳' => '穩',
+    '穷' => '窮',
+    '窃' => '竊',
+    '窍' => '竅',
+    '窜' => '竄',
+    '窝' => '窩',
+    '窥' => '窺',
+    '竖' => '豎',
+    '竞' => '競',
+    '笃' => '篤',
+    '笋' => '筍',
+    '笔' => '筆',
+    '笕' => '筧',
+    '笼' => '籠',
+    '筑' => '築',
+    '筛' => '篩',
+    '签' => '簽',
+    '简' => '簡',
+    '篮' => '籃',
+    '籁' => '籟',
+    '类' => '類',
+    '粪' => '糞',
+    '粮' => '糧',
+    '紧' => '緊',
+    '纠' => '糾',
+    '红' => '紅',
+    '纤' => '纖',
+    '约' => '約',
+    '级' => '級',
+    '纪' => '紀',
+    '纮' => '紘',
+    '纯' => '純',
+    '纱' => '紗',
+    '纲' => '綱',
+    '纳' => '納',
+    '纵' => '縱',
+    '纶' => '綸',
+    '纷' => '紛',
+    '纸' => '紙',
+    '纹' => '紋',
+    '纺' => '紡',
+    '纽' => '紐',
+    '线' => '線',
+    '绀' => '紺',
+    '绁' => '紲',
+    '练' => '練',
+    '组' => '組',
+    '绅' => '紳',
+    '细' => '細',
+    '织' => '織',
+    '终' => '終',
+    '绊' => '絆',
+    '经' => '經',
+    '绑' => '綁',
+    '绒' => '絨',
+    '结' => '結',
+    '绕' => '繞',
+    '绘' => '繪',
+    '给' => '給',
+    '绚' => '絢',
+    '绛' => '絳',
+    '络' => '絡',
+    '绝' => '絕',
+    '绞' => '絞',
+    '统' => '統',
+    '绢' => '絹',
+    '继' => '繼',
+    '绩' => '績',
+    '绪' => '緒',
+    '绫' => '綾',
+    '续' => '續',
+    '绮' => '綺',
+    '绯' => '緋',
+    '绳' => '繩',
+    '维' => '維',
+    '绵' => '綿',
+    '绷' => '繃',
+    '综' => '綜',
+    '绽' => '綻',
+    '绿' => '綠',
+    '缀' => '綴',
+    '缇' => '緹',
+    '缎' => '緞',
+    '缓' => '緩',
+    '编' => '編',
+    '缘' => '緣',
+    '缚' => '縛',
+    '缝' => '縫',
+    '缠' => '纏',
+    '缨' => '纓',
+    '缩' => '縮',
+    '缪' => '繆',
+    '缭' => '繚',
+    '罂' => '罌',
+    '网' => '網',
+    '罗' => '羅',
+    '罚' => '罰',
+    '罢' => '罷',
+    '罴' => '羆',
+    '羡' => '羨',
+    '翘' => '翹',
+    '耸' => '聳',
+    '耻' => '恥',
+    '聂' => '聶',
+    '聋' => '聾',
+    '职' => '職',
+    '联' => '聯',
+    '聪' => '聰',
+    '肃' => '肅',
+    '肠' => '腸',
+    '肤' => '膚',
+    '肾' => '腎',
+    '肿' => '腫',
+    '胀' => '脹',
+    '胁' => '脅',
+    '胆' => '膽',
+    '胜' => '勝',
+    '胧' => '朧',
+    '胶' => '膠',
+    '脉' => '脈',
+    '脏' => '髒',
+    '脐' => '臍',
+    '脑' => '腦',
+    '脚' => '腳',
+    '脱' => '脫',
+    '脸' => '臉',
+    '腊' => '臘',
+    '腻' => '膩',
+    '腼' => '靦',
+    '腾' => '騰',
+    '舆' => '輿',
+    '舰' => '艦',
+    '艰' => '艱',
+    '艳' => '豔',
+    '艺' => '藝',
+    '节' => '節',
+    '芜' => '蕪',
+    '芦' => '蘆',
+    '苇' => '葦',
+    '苌' => '萇',
+    '苍' => '蒼',
+    '苏' => '蘇',
+    '苹' => '蘋',
+    '茎' => '莖',
+    '茧' => '繭',
+    '荆' => '荊',
+    '荐' => '薦',
+    '荠' => '薺',
+    '荡' => '蕩',
+    '荣' => '榮',
+    '荧' => '熒',
+    '荫' => '蔭',
+    '药' => '藥',
+    '莱' => '萊',
+    '莲' => '蓮',
+    '莳' => '蒔',
+    '获' => '獲',
+    '莺' => '鶯',
+    '萝' => '蘿',
+    '萤' => '螢',
+    '营' => '營',
+    '萧' => '蕭',
+    '萨' => '薩',
+    '葱' => '蔥',
+    '蒋' => '蔣',
+    '蓝' => '藍',
+    '蓟' => '薊',
+    '蔷' => '薔',
+    '蔼' => '藹',
+    '蕴' => '蘊',
+    '薮' => '藪',
+    '虏' => '虜',
+    '虑' => '慮',
+    '虚' => '虛',
+    '虫' => '蟲',
+    '虽' => '雖',
+    '虾' => '蝦',
+    '蚀' => '蝕',
+    '蚁' => '蟻',
+    '蚂' => '螞',
+    '蛎' => '蠣',
+    '蛮' => '蠻',
+    '蜗' => '蝸',
+    '蜡' => '蠟',
+    '蝉' => '蟬',
+    '衅' => '釁',
+    '衔' => '銜',
+    '补' => '補',
+    '衬' => '襯',
+    '袄' => '襖',
+    '袜' => '襪',
+    '袭' => '襲',
+    '装' => '裝',
+    '裈' => '褌',
+    '裤' => '褲',
+    '见' => '見',
+    '观' => '觀',
+    '规' => '規',
+    '觅' => '覓',
+    '视' => '視',
+    '览' => '覽',
+    '觉' => '覺',
+    '誉' => '譽',
+    '计' => '計',
+    '认' => '認',
+    '讨' => '討',
+    '让' => '讓',
+    '训' => '訓',
+    '议' => '議',
+    '讯' => '訊',
+    '记' => '記',
+    '讲' => '講',
+    '讴' => '謳',
+    '许' => '許',
+    '论' => '論',
+    '设' => '設',
+    '访' => '訪',
+    '证' => '證',
+    '诃' => '訶',
+    '评' => '評',
+    '诅' => '詛',
+    '识' => '識',
+    '诈' => '詐',
+    '诉' => '訴',
+    '诊' => '診',
+    '词' => '詞',
+    '译' => '譯',
+    '试' => '試',
+    '诗' => '詩',
+    '诘' => '詰',
+    '诚' => '誠',
+    '话' => '話',
+    '诞' => '誕',
+    '诡' => '詭',
+    '询' => '詢',
+    '该' => '該',
+    '详' => '詳',
+    '诩' => '詡',
+    '语' => '語',
+    '误' => '誤',
+    '诱' => '誘',
+    '说' => '說',
+    '请' => '請',
+    '诸' => '諸',
+    '诹' => '諏',
+    '诺' => '諾',
+    '读' => '讀',
+    '课' => '課',
+    '谁' => '誰',
+    '调' => '調',
+    '谅' => '諒',
+    '谈' => '談',
+    '谊' => '誼',
+    '谋' => '謀',
+    '谍' => '諜',
+    '谎' => '謊',
+    '谏' => '諫',
+    '谓' => '謂',
+    '谕' => '諭',
+    '谛' => '諦',
+    '谜' => '謎',
+    '谡' => '謖',
+    '谢' => '謝',
+    '谣' => '謠',
+    '谦' => '謙',
+    '谧' => '謐',
+    '谨' => '謹',
+    '谭' => '譚',
+    '谱' => '譜',
+    '贝' => '貝',
+    '贞' => '貞',
+    '负' => '負',
+    '贡' => '貢',
+    '财' => '財',
+    '责' => '責',
+    '贤' => '賢',
+    '败' => '敗',
+    '货' => '貨',
+    '质' => '質',
+    '贩' => '販',
+    '贪' => '貪',
+    '贫' => '貧',
+    '购' => '購',
+    '贯' => '貫',
+    '贰' => '貳',
+    '贱' => '賤',
+    '贴' => '貼',
+    '贵' => '貴',
+    '贷' => '貸',
+    '贸' => '貿',
+    '费' => '費',
+    '贺' => '賀',
+    '贼' => '賊',
+    '贾' => '賈',
+    '资' => '資',
+    '赋' => '賦',
+    '赌' => '賭',
+    '赎' => '贖',
+    '赏' => '賞',
+    '赐' => '賜',
+    '赓' => '賡',
+    '赔' => '賠',
+    '赖' => '賴',
+    '赚' => '賺',
+    '赛' => '賽',
+    '赝' => '贗',
+    '赞' => '贊',
+    '赠' => '贈',
+    '赵' => '趙',
+    '赶' => '趕',
+    '趋' => '趨',
+    '跃' => '躍',
+    '践' => '踐',
+    '踪' => '蹤',
+    '躏' => '躪',
+    '车' => '車',
+    '轨' => '軌',
+    '轩' => '軒',
+    '转' => '轉',
+    '轮' => '輪',
+    '软' => '軟',
+    '轰' => '轟',
+    '轲' => '軻',
+    '轴' => '軸',
+    '轻' => '輕',
+    '载' => '載',
+    '较' => '較',
+    '辅' => '輔',
+    '辆' => '輛',
+    '辈' => '輩',
+    '辉' => '輝',
+    '辐' => '輻',
+    '输' => '輸',
+    '辖' => '轄',
+    '辞' => '辭',
+    '辫' => '辮',
+    '边' => '邊',
+    '辽' => '遼',
+    '达' => '達',
+    '迁' => '遷',
+    '过' => '過',
+    '迈' => '邁',
+    '运' => '運',
+    '还' => '還',
+    '这' => '這',
+    '进' => '進',
+    '远' => '遠',
+    '违' => '違',
+    '连' => '連',
+    '迟' => '遲',
+    '迹' => '跡',
+    '适' => '適',
+    '选' => '選',
+    '逊' => '遜',
+    '递' => '遞',
+    '逻' => '邏',
+    '遗' => '遺',
+    '遥' => '遙',
+    '邓' => '鄧',
+    '邬' => '鄔',
+    '邮' => '郵',
+    '邻' => '鄰',
+    '郑' => '鄭',
+    '酱' => '醬',
+    '酿' => '釀',
+    '释' => '釋',
+    '鉴' => '鑑',
+    '针' => '針',
+    '钉' => '釘',
+    '钓' => '釣',
+    '钙' => '鈣',
+    '钝' => '鈍',
+    '钟' => '鐘',
+    '钢' => '鋼',
+    '钥' => '鑰',
+    '钩' => '鉤',
+    '钱' => '錢',
+    '钻' => '鑽',
+    '钿' => '鈿',
+    '铁' => '鐵',
+    '铃' => '鈴',
+    '铅' => '鉛',
+    '铛' => '鐺',
+    '铜' => '銅',
+    '铠' => '鎧',
+    '铭' => '銘',
+    '铳' => '銃',
+    '银' => '銀',
+    '铸' => '鑄',
+    '铺' => '鋪',
+    '链' => '鏈',
+    '销' => '銷',
+    '锁' => '鎖',
+    '锅' => '鍋',
+    '锋' => '鋒',
+    '锏' => '鐧',
+    '锐' => '銳',
+    '锖' => '錆',
+    '错' => '錯',
+    '锡' => '錫',
+    '锤' => '錘',
+    '锦' => '錦',
+    '锭' => '錠',
+    '键' => '鍵',
+    '锯' => '鋸',
+    '锻' => '鍛',
+    '镇' => '鎮',
+    '镜' => '鏡',
+    '镫' => '鐙',
+    '镰' => '鐮',
+    '长' => '長',
+    '门' => '門',
+    '闪' => '閃',
+    '闭' => '閉',
+    '问' => '問',
+    '闯' => '闖',
+    '闲' => '閒',
+    '间' => '間',
+    '闷' => '悶',
+    '闹' => '鬧',
+    '闻' => '聞',
+    '阀' => '閥',
+    '阁' => '閣',
+    '阅' => '閱',
+    '阎' => '閻',
+    '阔' => '闊',
+    '队' => '隊',
+    '阳' => '陽',
+    '阴' => '陰',
+    '阵' => '陣',
+    '阶' => '階',
+    '际' => '際',
+    '陆' => '陸',
+    '陈' => '陳',
+    '陕' => '陝',
+    '险' => '險',
+    '随' => '隨',
+    '隐' => '隱',
+    '隶' => '隸',
+    '难' => '難',
+    '雏' => '雛',
+    '雳' => '靂',
+    '雾' => '霧',
+    '静' => '靜',
+    '韦' => '韋',
+    '韩' => '韓',
+    '韵' => '韻',
+    '页' => '頁',
+    '顶' => '頂',
+    '项' => '項',
+    '顺' => '順',
+    '须' => '須',
+    '顾' => '顧',
+    '顿' => '頓',
+    '颂' => '頌',
+    '预' => '預',
+    '领' => '領',
+    '颇' => '頗',
+    '颈' => '頸',
+    '频' => '頻',
+    '颗' => '顆',
+    '题' => '題',
+    '颜' => '顏',
+    '额' => '額',
+    '风' => '風',
+    '飒' => '颯',
+    '飙' => '飆',
+    '飚' => '飈',
+    '飞' => '飛',
+    '飨' => '饗',
+    '饥' => '飢',
+    '饭' => '飯',
+    '饮' => '飲',
+    '饰' => '飾',
+    '饱' => '飽',
+    '饲' => '飼',
+    '饴' => '飴',
+    '饺' => '餃',
+    '饼' => '餅',
+    '饿' => '餓',
+    '馁' => '餒',
+    '馅' => '餡',
+    '馆' => '館',
+    '馒' => '饅',
+    '马' => '馬',
+    '驭' => '馭',
+    '驯' => '馴',
+    '驰' => '馳',
+    '驱' => '驅',
+    '驴' => '驢',
+    '驶' => '駛',
+    '驹' => '駒',
+    '驻' => '駐',
+    '驼' => '駝',
+    '驾' => '駕',
+    '骂' => '罵',
+    '骃' => '駰',
+    '骄' => '驕',
+    '骆' => '駱',
+    '骇' => '駭',
+    '骊' => '驪',
+    '验' => '驗',
+    '骏' => '駿',
+    '骑' => '騎',
+    '骗' => '騙',
+    '骚' => '騷',
+    '骤' => '驟',
+    '骥' => '驥',
+    '骧' => '驤',
+    '髅' => '髏',
+    '鱼' => '魚',
+    '鲁' => '魯',
+    '鲇' => '鮎',
+    '鲍' => '鮑',
+    '鲐' => '鮐',
+    '鲑' => '鮭',
+    '鲛' => '鮫',
+    '鲜' => '鮮',
+    '鲟' => '鱘',
+    '鲤' => '鯉',
+    '鲨' => '鯊',
+    '鲭' => '鯖',
+    '鲶' => '鯰',
+    '鲷' => '鯛',
+    '鲸' => '鯨',
+    '鳄' => '鱷',
+    '鳉' => '鱂',
+    '鳌' => '鰲',
+    '鳍' => '鰭',
+    '鳏' => '鰥',
+    '鳐' => '鰩',
+    '鳕' => '鱈',
+    '鳗' => '鰻',
+    '鳞' => '鱗',
+    '鸟' => '鳥',
+    '鸠' => '鳩',
+    '鸡' => '雞',
+    '鸢' => '鳶',
+    '鸣' => '鳴',
+    '鸥' => '鷗',
+    '鸦' => '鴉',
+    '鸨' => '鴇',
+    '鸫' => '鶇',
+    '鸭' => '鴨',
+    '鸮' => '鴞',
+    '鸰' => '鴒',
+    '鸱' => '鴟',
+    '鸵' => '鴕',
+    '鸽' => '鴿',
+    '鸾' => '鸞',
+    '鸿' => '鴻',
+    '鹂' => '鸝',
+    '鹃' => '鵑',
+    '鹅' => '鵝',
+    '鹈' => '鵜',
+    '鹖' => '鶡',
+    '鹞' => '鷂',
+    '鹡' => '鶺',
+    '鹤' => '鶴',
+    '鹩' => '鷯',
+    '鹫' => '鷲',
+    '鹬' => '鷸',
+    '鹭' => '鷺',
+    '鹰' => '鷹',
+    '鹳' => '鸛',
+    '麦' => '麥',
+    '麸' => '麩',
+    '黄' => '黃',
+    '齐' => '齊',
+    '齿' => '齒',
+    '龄' => '齡',
+    '龙' => '龍',
+    '龟' => '龜',
+};
+
+// Traditional -> Simplified, for search input
+pub static ZH_HANS: phf::Map<char, char> = phf::phf_map! {
+    '丟' => '丢',
+    '並' => '并',
+    '亂' => '乱',
+    '亞' => '亚',
+    '來' => '来',
+    '侖' => '仑',
+    '侶' => '侣',
+    '俠' => '侠',
+    '倆' => '俩',
+    '倉' => '仓',
+    '個' => '个',
+    '們' => '们',
+    '倫' => '伦',
+    '偉' => '伟',
+    '側' => '侧',
+    '偵' => '侦',
+    '偽' => '伪',
+    '傑' => '杰',
+    '傘' => '伞',
+    '備' => '备',
+    '傭' => '佣',
+    '傳' => '传',
+    '債' => '债',
+    '傷' => '伤',
+    '傾' => '倾',
+    '僅' => '仅',
+    '僑' => '侨',
+    '僥' => '侥',
+    '價' => '价',
+    '儀' => '仪',
+    '儂' => '侬',
+    '億' => '亿',
+    '儉' => '俭',
+    '儔' => '俦',
+    '償' => '偿',
+    '優' => '优',
+    '儲' => '储',
+    '儷' => '俪',
+    '儺' => '傩',
+    '兌' => '兑',
+    '兒' => '儿',
+    '內' => '内',
+    '兩' => '两',
+    '冊' => '册',
+    '凍' => '冻',
+    '凜' => '凛',
+    '凱' => '凯',
+    '別' => '别',
+    '刪' => '删',
+    '則' => '则',
+    '剎' => '刹',
+    '剛' => '刚',
+    '剝' => '剥',
+    '剮' => '剐',
+    '創' => '创',
+    '劃' => '划',
+    '劇' => '剧',
+    '劉' => '刘',
+    '劊' => '刽',
+    '劍' => '剑',
+    '劑' => '剂',
+    '勁' => '劲',
+    '動' => '动',
+    '務' => '务',
+    '勝' => '胜',
+    '勞' => '劳',
+    '勢' => '势',
+    '勳' => '勋',
+    '勵' => '励',
+    '勸' => '劝',
+    '勻' => '匀',
+    '匯' => '汇',
+    '區' => '区',
+    '協' => '协',
+    '卻' => '却',
+    '厭' => '厌',
+    '厲' => '厉',
+    '參' => '参',
+    '叄' => '叁',
+    '叢' => '丛',
+    '吳' => '吴',
+    '吶' => '呐',
+    '呂' => '吕',
+    '員' => '员',
+    '唄' => '呗',
+    '問' => '问',
+    '啞' => '哑',
+    '啟' => '启',
+    '喚' => '唤',
+    '喪' => '丧',
+    '喬' => '乔',
+    '單' => '单',
+    '喲' => '哟',
+    '嗆' => '呛',
+    '嗎' => '吗',
+    '嗚' => '呜',
+    '嗶' => '哔',
+    '嘆' => '叹',
+    '嘔' => '呕',
+    '嘗' => '尝',
+    '嘩' => '哗',
+    '嘯' => '啸',
+    '嘰' => '叽',
+    '噓' => '嘘',
+    '噠' => '哒',
+    '噴' => '喷',
+    '噸' => '吨',
+    '嚇' => '吓',
+    '嚕' => '噜',
+    '嚙' => '啮',
+    '嚨' => '咙',
+    '嚴' => '严',
+    '囂' => '嚣',
+    '囉' => '啰',
+    '囑' => '嘱',
+    '國' => '国',
+    '圍' => '围',
+    '園' => '园',
+    '圓' => '圆',
+    '圖' => '图',
+    '團' => '团',
+    '埡' => '垭',
+    '執' => '执',
+    '堅' => '坚',
+    '堯' => '尧',
+    '報' => '报',
+    '場' => '场',
+    '塊' => '块',
+    '塗' => '涂',
+    '塢' => '坞',
+    '塵' => '尘',
+    '墜' => '坠',
+    '墮' => '堕',
+    '墳' => '坟',
+    '墾' => '垦',
+    '壇' => '坛',
+    '壓' => '压',
+    '壘' => '垒',
+    '壞' => '坏',
+    '壟' => '垄',
+    '壩' => '坝',
+    '壯' => '壮',
+    '壺' => '壶',
+    '壽' => '寿',
+    '夠' => '够',
+    '夢' => '梦',
+    '夾' => '夹',
+    '奪' => '夺',
+    '奮' => '奋',
+    '妝' => '妆',
+    '姍' => '姗',
+    '娛' => '娱',
+    '婁' => '娄',
+    '婦' => '妇',
+    '婭' => '娅',
+    '媽' => '妈',
+    '嬌' => '娇',
+    '嬰' => '婴',
+    '嬸' => '婶',
+    '孫' => '孙',
+    '學' => '学',
+    '孿' => '孪',
+    '宮' => '宫',
+    '寢' => '寝',
+    '實' => '实',
+    '寧' => '宁',
+    '審' => '审',
+    '寫' => '写',
+    '寬' => '宽',
+    '寵' => '宠',
+    '寶' => '宝',
+    '將' => '将',
+    '專' => '专',
+    '尋' => '寻',
+    '對' => '对',
+    '導' => '导',
+    '尷' => '尴',
+    '屆' => '届',
+    '屍' => '尸',
+    '屜' => '屉',
+    '屢' => '屡',
+    '層' => '层',
+    '屬' => '属',
+    '岡' => '冈',
+    '島' => '岛',
+    '峽' => '峡',
+    '崗' => '岗',
+    '嵐' => '岚',
+    '嶄' => '崭',
+    '嶺' => '岭',
+    '嶼' => '屿',
+    '巒' => '峦',
+    '巔' => '巅',
+    '帥' => '帅',
+    '師' => '师',
+    '帳' => '帐',
+    '帶' => '带',
+    '幟' => '帜',
+    '幣' => '币',
+    '幫' => '帮',
+    '幾' => '几',
+    '庫' => '库',
+    '廁' => '厕',
+    '廂' => '厢',
+    '廈' => '厦',
+    '廚' => '厨',
+    '廟' => '庙',
+    '廠' => '厂',
+    '廢' => '废',
+    '廣' => '广',
+    '廳' => '厅',
+    '弒' => '弑',
+    '張' => '张',
+    '強' => '强',
+    '彈' => '弹',
+    '彌' => '弥',
+    '彎' => '弯',
+    '彥' => '彦',
+    '徑' => '径',
+    '從' => '从',
+    '復' => '复',
+    '徹' => '彻',
+    '恆' => '恒',
+    '恥' => '耻',
+    '悅' => '悦',
+    '悶' => '闷',
+    '惡' => '恶',
+    '惱' => '恼',
+    '愛' => '爱',
+    '態' => '态',
+    '慘' => '惨',
+    '慚' => '惭',
+    '慣' => '惯',
+    '慫' => '怂',
+    '慮' => '虑',
+    '慶' => '庆',
+    '憂' => '忧',
+    '憊' => '惫',
+    '憐' => '怜',
+    '憑' => '凭',
+    '憤' => '愤',
+    '憫' => '悯',
+    '憲' => '宪',
+    '憶' => '忆',
+    '懇' => '恳',
+    '應' => '应',
+    '懲' => '惩',
+    '懶' => '懒',
+    '懷' => '怀',
+    '懸' => '悬',
+    '懺' => '忏',
+    '懼' => '惧',
+    '懾' => '慑',
+    '戀' => '恋',
+    '戰' => '战',
+    '戲' => '戏',
+    '戶' => '户',
+    '拋' => '抛',
+    '挾' => '挟',
+    '掃' => '扫',
+    '掙' => '挣',
+    '掛' => '挂',
+    '揚' => '扬',
+    '換' => '换',
+    '揮' => '挥',
+    '損' => '损',
+    '搖' => '摇',
+    '搗' => '捣',
+    '搶' => '抢',
+    '摯' => '挚',
+    '撈' => '捞',
+    '撐' => '撑',
+    '撓' => '挠',
+    '撣' => '掸',
+    '撥' => '拨',
+    '撫' => '抚',
+    '撻' => '挞',
+    '撿' => '捡',
+    '擁' => '拥',
+    '擄' => '掳',
+    '擇' => '择',
+    '擊' => '击',
+    '擋' => '挡',
+    '擔' => '担',
+    '據' => '据',
+    '擠' => '挤',
+    '擬' => '拟',
+    '擰' => '拧',
+    '擲' => '掷',
+    '擴' => '扩',
+    '擺' => '摆',
+    '擾' => '扰',
+    '攆' => '撵',
+    '攏' => '拢',
+    '攔' => '拦',
+    '攙' => '搀',
+    '攜' => '携',
+    '攝' => '摄',
+    '攤' => '摊',
+    '攬' => '揽',
+    '敗' => '败',
+    '敘' => '叙',
+    '敵' => '敌',
+    '數' => '数',
+    '斃' => '毙',
+    '斕' => '斓',
+    '斬' => '斩',
+    '斷' => '断',
+    '於' => '于',
+    '時' => '时',
+    '晉' => '晋',
+    '晝' => '昼',
+    '暈' => '晕',
+    '暉' => '晖',
+    '暢' => '畅',
+    '暫' => '暂',
+    '曇' => '昙',
+    '曉' => '晓',
+    '曠' => '旷',
+    '曬' => '晒',
+    '書' => '书',
+    '會' => '会',
+    '朧' => '胧',
+    '東' => '东',
+    '梔' => '栀',
+    '條' => '条',
+    '梟' => '枭',
+    '棄' => '弃',
+    '棖' => '枨',
+    '棗' => '枣',
+    '棟' => '栋',
+    '棧' => '栈',
+    '楊' => '杨',
+    '楓' => '枫',
+    '業' => '业',
+    '極' => '极',
+    '榮' => '荣',
+    '構' => '构',
+    '槍' => '枪',
+    '樂' => '乐',
+    '樓' => '楼',
+    '標' => '标',
+    '樞' => '枢',
+    '樣' => '样',
+    '樹' => '树',
+    '樺' => '桦',
+    '橋' => '桥',
+    '機' => '机',
+    '橢' => '椭',
+    '橫' => '横',
+    '檔' => '档',
+    '檜' => '桧',
+    '檢' => '检',
+    '檳' => '槟',
+    '檸' => '柠',
+    '檻' => '槛',
+    '櫃' => '柜',
+    '櫓' => '橹',
+    '櫚' => '榈',
+    '櫛' => '栉',
+    '櫟' => '栎',
+    '櫻' => '樱',
+    '欄' => '栏',
+    '權' => '权',
+    '欖' => '榄',
+    '歐' => '欧',
+    '歡' => '欢',
+    '歲' => '岁',
+    '歷' => '历',
+    '歸' => '归',
+    '殘' => '残',
+    '殲' => '歼',
+    '殺' => '杀',
+    '殼' => '壳',
+    '毀' => '毁',
+    '毆' => '殴',
+    '氣' => '气',
+    '決' => '决',
+    '沒' => '没',
+    '況' => '况',
+    '涼' => '凉',
+    '淚' => '泪',
+    '淨' => '净',
+    '淪' => '沦',
+    '淵' => '渊',
+    '淺' => '浅',
+    '減' => '减',
+    '渦' => '涡',
+    '測' => '测',
+    '渾' => '浑',
+    '湊' => '凑',
+    '湯' => '汤',
+    '準' => '准',
+    '溝' => '沟',
+    '溫' => '温',
+    '滄' => '沧',
+    '滅' => '灭',
+    '滌' => '涤',
+    '滬' => '沪',
+    '滷' => '卤',
+    '滸' => '浒',
+    '滾' => '滚',
+    '滿' => '满',
+    '漁' => '渔',
+    '漢' => '汉',
+    '漣' => '涟',
+    '漲' => '涨',
+    '漸' => '渐',
+    '漿' => '浆',
+    '潑' => '泼',
+    '潔' => '洁',
+    '潛' => '潜',
+    '潤' => '润',
+    '潰' => '溃',
+    '澀' => '涩',
+    '澆' => '浇',
+    '澤' => '泽',
+    '濁' => '浊',
+    '濃' => '浓',
+    '濕' => '湿',
+    '濘' => '泞',
+    '濟' => '济',
+    '濤' => '涛',
+    '濫' => '滥',
+    '濱' => '滨',
+    '濺' => '溅',
+    '濾' => '滤',
+    '瀉' => '泻',
+    '瀏' => '浏',
+    '瀟' => '潇',
+    '瀧' => '泷',
+    '灑' => '洒',
+    '灘' => '滩',
+    '灣' => '湾',
+    '災' => '灾',
+    '為' => '为',
+    '烏' => '乌',
+    '無' => '无',
+    '煉' => '炼',
+    '煙' => '烟',
+    '煥' => '焕',
+    '煩' => '烦',
+    '熒' => '荧',
+    '熱' => '热',
+    '熾' => '炽',
+    '燁' => '烨',
+    '燈' => '灯',
+    '燒' => '烧',
+    '營' => '营',
+    '燦' => '灿',
+    '燭' => '烛',
+    '爍' => '烁',
+    '爐' => '炉',
+    '爛' => '烂',
+    '爭' => '争',
+    '爺' => '爷',
+    '爾' => '尔',
+    '牆' => '墙',
+    '牽' => '牵',
+    '狀' => '状',
+    '狹' => '狭',
+    '狽' => '狈',
+    '猶' => '犹',
+    '獄' => '狱',
+    '獅' => '狮',
+    '獎' => '奖',
+    '獨' => '独',
+    '獫' => '猃',
+    '獲' => '获',
+    '獵' => '猎',
+    '獸' => '兽',
+    '獺' => '獭',
+    '獻' => '献',
+    '現' => '现',
+    '琺' => '珐',
+    '瑣' => '琐',
+    '瑤' => '瑶',
+    '瑪' => '玛',
+    '環' => '环',
+    '璽' => '玺',
+    '瓊' => '琼',
+    '瓔' => '璎',
+    '產' => '产',
+    '畝' => '亩',
+    '畢' => '毕',
+    '畫' => '画',
+    '異' => '异',
+    '當' => '当',
+    '疊' => '叠',
+    '瘋' => '疯',
+    '瘡' => '疮',
+    '療' => '疗',
+    '癡' => '痴',
+    '癢' => '痒',
+    '癱' => '瘫',
+    '發' => '发',
+    '盜' => '盗',
+    '盞' => '盏',
+    '盡' => '尽',
+    '監' => '监',
+    '盤' => '盘',
+    '盧' => '卢',
+    '眾' => '众',
+    '睜' => '睁',
+    '矯' => '矫',
+    '硯' => '砚',
+    '碩' => '硕',
+    '確' => '确',
+    '碼' => '码',
+    '磚' => '砖',
+    '磯' => '矶',
+    '礎' => '础',
+    '礙' => '碍',
+    '礫' => '砾',
+    '祿' => '禄',
+    '禍' => '祸',
+    '禪' => '禅',
+    '禮' => '礼',
+    '禰' => '祢',
+    '禱' => '祷',
+    '禿' => '秃',
+    '種' => '种',
+    '稱' => '称',
+    '積' => '积',
+    '穢' => '秽',
+    '穩' => '稳',
+    '窩' => '窝',
+    '窮' => '穷',
+    '窺' => '窥',
+    '竄' => '窜',
+    '竅' => '窍',
+    '竊' => '窃',
+    '競' => '竞',
+    '筆' => '笔',
+    '筍' => '笋',
+    '筧' => '笕',
+    '節' => '节',
+    '築' => '筑',
+    '篤' => '笃',
+    '篩' => '筛',
+    '簡' => '简',
+    '簽' => '签',
+    '籃' => '篮',
+    '籟' => '籁',
+    '籠' => '笼',
+    '糞' => '粪',
+    '糧' => '粮',
+    '糾' => '纠',
+    '紀' => '纪',
+    '約' => '约',
+    '紅' => '红',
+    '紋' => '纹',
+    '納' => '纳',
+    '紐' => '纽',
+    '純' => '纯',
+    '紗' => '纱',
+    '紘' => '纮',
+    '紙' => '纸',
+    '級' => '级',
+    '紛' => '纷',
+    '紡' => '纺',
+    '細' => '细',
+    '紲' => '绁',
+    '紳' => '绅',
+    '紺' => '绀',
+    '終' => '终',
+    '組' => '组',
+    '絆' => '绊',
+    '結' => '结',
+    '絕' => '绝',
+    '絞' => '绞',
+    '絡' => '络',
+    '絢' => '绚',
+    '給' => '给',
+    '絨' => '绒',
+    '統' => '统',
+    '絲' => '丝',
+    '絳' => '绛',
+    '絹' => '绢',
+    '綁' => '绑',
+    '經' => '经',
+    '綜' => '综',
+    '綠' => '绿',
+    '維' => '维',
+    '綱' => '纲',
+    '網' => '网',
+    '綴' => '缀',
+    '綸' => '纶',
+    '綺' => '绮',
+    '綻' => '绽',
+    '綾' => '绫',
+    '綿' => '绵',
+    '緊' => '紧',
+    '緋' => '绯',
+    '緒' => '绪',
+    '線' => '线',
+    '緞' => '缎',
+    '緣' => '缘',
+    '編' => '编',
+    '緩' => '缓',
+    '練' => '练',
+    '緹' => '缇',
+    '縛' => '缚',
+    '縣' => '县',
+    '縫' => '缝',
+    '縮' => '缩',
+    '縱' => '纵',
+    '總' => '总',
+    '績' => '绩',
+    '繃' => '绷',
+    '繆' => '缪',
+    '織' => '织',
+    '繚' => '缭',
+    '繞' => '绕',
+    '繩' => '绳',
+    '繪' => '绘',
+    '繭' => '茧',
+    '繼' => '继',
+    '續' => '续',
+    '纏' => '缠',
+    '纓' => '缨',
+    '纖' => '纤',
+    '罌' => '罂',
+    '罰' => '罚',
+    '罵' => '骂',
+    '罷' => '罢',
+    '羅' => '罗',
+    '羆' => '罴',
+    '羨' => '羡',
+    '義' => '义',
+    '習' => '习',
+    '翹' => '翘',
+    '聖' => '圣',
+    '聞' => '闻',
+    '聯' => '联',
+    '聰' => '聪',
+    '聲' => '声',
+    '聳' => '耸',
+    '聶' => '聂',
+    '職' => '职',
+    '聽' => '听',
+    '聾' => '聋',
+    '肅' => '肃',
+    '脅' => '胁',
+    '脈' => '脉',
+    '脫' => '脱',
+    '脹' => '胀',
+    '腎' => '肾',
+    '腦' => '脑',
+    '腫' => '肿',
+    '腳' => '脚',
+    '腸' => '肠',
+    '膚' => '肤',
+    '膠' => '胶',
+    '膩' => '腻',
+    '膽' => '胆',
+    '臉' => '脸',
+    '臍' => '脐',
+    '臘' => '腊',
+    '臨' => '临',
+    '興' => '兴',
+    '舉' => '举',
+    '舊' => '旧',
+    '艦' => '舰',
+    '艱' => '艰',
+    '芻' => '刍',
+    '茲' => '兹',
+    '荊' => '荆',
+    '莊' => '庄',
+    '莖' => '茎',
+    '華' => '华',
+    '萇' => '苌',
+    '萊' => '莱',
+    '葉' => '叶',
+    '著' => '着',
+    '葦' => '苇',
+    '蒔' => '莳',
+    '蒼' => '苍',
+    '蓋' => '盖',
+    '蓮' => '莲',
+    '蔣' => '蒋',
+    '蔥' => '葱',
+    '蔭' => '荫',
+    '蕩' => '荡',
+    '蕪' => '芜',
+    '蕭' => '萧',
+    '薊' => '蓟',
+    '薔' => '蔷',
+    '薦' => '荐',
+    '薩' => '萨',
+    '薺' => '荠',
+    '藍' => '蓝',
+    '藝' => '艺',
+    '藥' => '药',
+    '藪' => '薮',
+    '藹' => '蔼',
+    '蘆' => '芦',
+    '蘇' => '苏',
+    '蘊' => '蕴',
+    '蘋' => '苹',
+    '蘭' => '兰',
+    '蘿' => '萝',
+    '處' => '处',
+    '虛' => '虚',
+    '虜' => '虏',
+    '號' => '号',
+    '虧' => '亏',
+    '蝕' => '蚀',
+    '蝟' => '猬',
+    '蝦' => '虾',
+    '蝸' => '蜗',
+    '螞' => '蚂',
+    '螢' => '萤',
+    '蟬' => '蝉',
+    '蟲' => '虫',
+    '蟻' => '蚁',
+    '蠟' => '蜡',
+    '蠣' => '蛎',
+    '蠻' => '蛮',
+    '術' => '术',
+    '衛' => '卫',
+    '衝' => '冲',
+    '補' => '补',
+    '裝' => '装',
+    '褌' => '裈',
+    '褲' => '裤',
+    '褻' => '亵',
+    '襖' => '袄',
+    '襪' => '袜',
+    '襯' => '衬',
+    '襲' => '袭',
+    '見' => '见',
+    '規' => '规',
+    '覓' => '觅',
+    '視' => '视',
+    '親' => '亲',
+    '覺' => '觉',
+    '覽' => '览',
+    '觀' => '观',
+    '計' => '计',
+    '訊' => '讯',
+    '討' => '讨',
+    '訓' => '训',
+    '記' => '记',
+    '訪' => '访',
+    '設' => '设',
+    '許' => '许',
+    '訴' => '诉',
+    '訶' => '诃',
+    '診' => '诊',
+    '詐' => '诈',
+    '評' => '评',
+    '詛' => '诅',
+    '詞' => '词',
+    '詠' => '咏',
+    '詡' => '诩',
+    '詢' => '询',
+    '試' => '试',
+    '詩' => '诗',
+    '詭' => '诡',
+    '詰' => '诘',
+    '話' => '话',
+    '該' => '该',
+    '詳' => '详',
+    '誇' => '夸',
+    '認' => '认',
+    '誕' => '诞',
+    '誘' => '诱',
+    '語' => '语',
+    '誠' => '诚',
+    '誤' => '误',
+    '說' => '说',
+    '誰' => '谁',
+    '課' => '课',
+    '誼' => '谊',
+    '調' => '调',
+    '談' => '谈',
+    '請' => '请',
+    '諏' => '诹',
+    '諒' => '谅',
+    '論' => '论',
+    '諜' => '谍',
+    '諦' => '谛',
+    '諫' => '谏',
+    '諭' => '谕',
+    '諸' => '诸',
+    '諾' => '诺',
+    '謀' => '谋',
+    '謂' => '谓',
+    '謊' => '谎',
+    '謎' => '谜',
+    '謐' => '谧',
+    '謖' => '谡',
+    '謙' => '谦',
+    '講' => '讲',
+    '謝' => '谢',
+    '謠' => '谣',
+    '謳' => '讴',
+    '謹' => '谨',
+    '證' => '证',
+    '識' => '识',
+    '譚' => '谭',
+    '譜' => '谱',
+    '譯' => '译',
+    '議' => '议',
+    '護' => '护',
+    '譽' => '誉',
+    '讀' => '读',
+    '變' => '变',
+    '讓' => '让',
+    '豈' => '岂',
+    '豎' => '竖',
+    '豐' => '丰',
+    '豔' => '艳',
+    '豬' => '猪',
+    '貓' => '猫',
+    '貝' => '贝',
+    '貞' => '贞',
+    '負' => '负',
+    '財' => '财',
+    '貢' => '贡',
+    '貧' => '贫',
+    '貨' => '货',
+    '販' => '贩',
+    '貪' => '贪',
+    '貫' => '贯',
+    '責' => '责',
+    '貳' => '贰',
+    '貴' => '贵',
+    '買' => '买',
+    '貸' => '贷',
+    '費' => '费',
+    '貼' => '贴',
+    '貿' => '贸',
+    '賀' => '贺',
+    '資' => '资',
+    '賈' => '贾',
+    '賊' => '贼',
+    '賓' => '宾',
+    '賜' => '赐',
+    '賞' => '赏',
+    '賠' => '赔',
+    '賡' => '赓',
+    '賢' => '贤',
+    '賣' => '卖',
+    '賤' => '贱',
+    '賦' => '赋',
+    '質' => '质',
+    '賭' => '赌',
+    '賴' => '赖',
+    '賺' => '赚',
+    '購' => '购',
+    '賽' => '赛',
+    '贈' => '赠',
+    '贊' => '赞',
+    '贖' => '赎',
+    '贗' => '赝',
+    '趕' => '赶',
+    '趙' => '赵',
+    '趨' => '趋',
+    '跡' => '迹',
+    '踐' => '践',
+    '蹤' => '踪',
+    '躍' => '跃',
+    '躪' => '躏',
+    '車' => '车',
+    '軌' => '轨',
+    '軍' => '军',
+    '軒' => '轩',
+    '軟' => '软',
+    '軸' => '轴',
+    '軻' => '轲',
+    '較' => '较',
+    '載' => '载',
+    '輔' => '辅',
+    '輕' => '轻',
+    '輛' => '辆',
+    '輝' => '辉',
+    '輩' => '辈',
+    '輪' => '轮',
+    '輸' => '输',
+    '輻' => '辐',
+    '輿' => '舆',
+    '轄' => '辖',
+    '轉' => '转',
+    '轟' => '轰',
+    '辦' => '办',
+    '辭' => '辞',
+    '辮' => '辫',
+    '農' => '农',
+    '這' => '这',
+    '連' => '连',
+    '進' => '进',
+    '運' => '运',
+    '過' => '过',
+    '達' => '达',
+    '違' => '违',
+    '遙' => '遥',
+    '遜' => '逊',
+    '遞' => '递',
+    '遠' => '远',
+    '適' => '适',
+    '遲' => '迟',
+    '遷' => '迁',
+    '選' => '选',
+    '遺' => '遗',
+    '遼' => '辽',
+    '邁' => '迈',
+    '還' => '还',
+    '邊' => '边',
+    '邏' => '逻',
+    '郵' => '邮',
+    '鄉' => '乡',
+    '鄔' => '邬',
+    '鄧' => '邓',
+    '鄭' => '郑',
+    '鄰' => '邻',
+    '醫' => '医',
+    '醬' => '酱',
+    '釀' => '酿',
+    '釁' => '衅',
+    '釋' => '释',
+    '釘' => '钉',
+    '針' => '针',
+    '釣' => '钓',
+    '鈍' => '钝',
+    '鈣' => '钙',
+    '鈴' => '铃',
+    '鈿' => '钿',
+    '鉛' => '铅',
+    '鉤' => '钩',
+    '銀' => '银',
+    '銃' => '铳',
+    '銅' => '铜',
+    '銘' => '铭',
+    '銜' => '衔',
+    '銳' => '锐',
+    '銷' => '销',
+    '鋒' => '锋',
+    '鋪' => '铺',
+    '鋸' => '锯',
+    '鋼' => '钢',
+    '錄' => '录',
+    '錆' => '锖',
+    '錘' => '锤',
+    '錠' => '锭',
+    '錢' => '钱',
+    '錦' => '锦',
+    '錫' => '锡',
+    '錯' => '错',
+    '鍋' => '锅',
+    '鍛' => '锻',
+    '鍵' => '键',
+    '鎖' => '锁',
+    '鎧' => '铠',
+    '鎮' => '镇',
+    '鏈' => '链',
+    '鏡' => '镜',
+    '鐘' => '钟',
+    '鐙' => '镫',
+    '鐧' => '锏',
+    '鐮' => '镰',
+    '鐵' => '铁',
+    '鐺' => '铛',
+    '鑄' => '铸',
+    '鑑' => '鉴',
+    '鑰' => '钥',
+    '鑽' => '钻',
+    '鑿' => '凿',
+    '長' => '长',
+    '門' => '门',
+    '閃' => '闪',
+    '閉' => '闭',
+    '開' => '开',
+    '閒' => '闲',
+    '間' => '间',
+    '閣' => '阁',
+    '閥' => '阀',
+    '閱' => '阅',
+    '閻' => '阎',
+    '闊' => '阔',
+    '闖' => '闯',
+    '關' => '关',
+    '陝' => '陕',
+    '陣' => '阵',
+    '陰' => '阴',
+    '陳' => '陈',
+    '陸' => '陆',
+    '陽' => '阳',
+    '隊' => '队',
+    '階' => '阶',
+    '際' => '际',
+    '隨' => '随',
+    '險' => '险',
+    '隱' => '隐',
+    '隸' => '隶',
+    '雖' => '虽',
+    '雙' => '双',
+    '雛' => '雏',
+    '雜' => '杂',
+    '雞' => '鸡',
+    '離' => '离',
+    '難' => '难',
+    '雲' => '云',
+    '電' => '电',
+    '霧' => '雾',
+    '靂' => '雳',
+    '靈' => '灵',
+    '靜' => '静',
+    '靦' => '腼',
+    '鞏' => '巩',
+    '韋' => '韦',
+    '韓' => '韩',
+    '韻' => '韵',
+    '響' => '响',
+    '頁' => '页',
+    '頂' => '顶',
+    '項' => '项',
+    '順' => '顺',
+    '須' => '须',
+    '頌' => '颂',
+    '預' => '预',
+    '頓' => '顿',
+    '頗' => '颇',
+    '領' => '领',
+    '頭' => '头',
+    '頸' => '颈',
+    '頻' => '频',
+    '顆' => '颗',
+    '題' => '题',
+    '額' => '额',
+    '顏' => '颜',
+    '願' => '愿',
+    '類' => '类',
+    '顧' => '顾',
+    '顯' => '显',
+    '風' => '风',
+    '颯' => '飒',
+    '飆' => '飙',
+    '飈' => '飚',
+    '飛' => '飞',
+    '飢' => '饥',
+    '飯' => '饭',
+    '飲' => '饮',
+    '飴' => '饴',
+    '飼' => '饲',
+    '飽' => '饱',
+    '飾' => '饰',
+    '餃' => '饺',
+    '餅' => '饼',
+    '養' => '养',
+    '餒' => '馁',
+    '餓' => '饿',
+    '餡' => '馅',
+    '館' => '馆',
+    '饅' => '馒',
+    '饗' => '飨',
+    '馬' => '马',
+    '馭' => '驭',
+    '馮' => '冯',
+    '馳' => '驰',
+    '馴' => '驯',
+    '駐' => '驻',
+    '駒' => '驹',
+    '駕' => '驾',
+    '駛' => '驶',
+    '駝' => '驼',
+    '駭' => '骇',
+    '駰' => '骃',
+    '駱' => '骆',
+    '駿' => '骏',
+    '騎' => '骑',
+    '騙' => '骗',
+    '騰' => '腾',
+    '騷' => '骚',
+    '驅' => '驱',
+    '驕' => '骄',
+    '驗' => '验',
+    '驚' => '惊',
+    '驟' => '骤',
+    '驢' => '驴',
+    '驤' => '骧',
+    '驥' => '骥',
+    '驪' => '骊',
+    '髏' => '髅',
+    '髒' => '脏',
+    '體' => '体',
+    '鬧' => '闹',
+    '魚' => '鱼',
+    '魯' => '鲁',
+    '鮎' => '鲇',
+    '鮐' => '鲐',
+    '鮑' => '鲍',
+    '鮫' => '鲛',
+    '鮭' => '鲑',
+    '鮮' => '鲜',
+    '鯉' => '鲤',
+    '鯊' => '鲨',
+    '鯖' => '鲭',
+    '鯛' => '鲷',
+    '鯨' => '鲸',
+    '鯰' => '鲶',
+    '鰥' => '鳏',
+    '鰩' => '鳐',
+    '鰭' => '鳍',
+    '鰲' => '鳌',
+    '鰻' => '鳗',
+    '鱂' => '鳉',
+    '鱈' => '鳕',
+    '鱗' => '鳞',
+    '鱘' => '鲟',
+    '鱷' => '鳄',
+    '鳥' => '鸟',
+    '鳩' => '鸠',
+    '鳳' => '凤',
+    '鳴' => '鸣',
+    '鳶' => '鸢',
+    '鴇' => '鸨',
+    '鴉' => '鸦',
+    '鴒' => '鸰',
+    '鴕' => '鸵',
+    '鴞' => '鸮',
+    '鴟' => '鸱',
+    '鴨' => '鸭',
+    '鴻' => '鸿',
+    '鴿' => '鸽',
+    '鵑' => '鹃',
+    '鵜' => '鹈',
+    '鵝' => '鹅',
+    '鶇' => '鸫',
+    '鶡' => '鹖',
+    '鶯' => '莺',
+    '鶴' => '鹤',
+    '鶺' => '鹡',
+    '鷂' => '鹞',
+    '鷗' => '鸥',
+    '鷯' => '鹩',
+    '鷲' => '鹫',
+    '鷸' => '鹬',
+    '鷹' => '鹰',
+    '鷺' => '鹭',
+    '鸛' => '鹳',
+    '鸝' => '鹂',
+    '鸞' => '鸾',
+    '鹽' => '盐',
+    '麗' => '丽',
+    '麥' => '麦',
+    '麩' => '麸',
+    '麼' => '么',
+    '黃' => '黄',
+    '點' => '点',
+    '齊' => '齐',
+    '齋' => '斋',
+    '齒' => '齿',
+    '齡' => '龄',
+    '龍' => '龙',
+    '龐' => '庞',
+    '龜' => '龟',
+};
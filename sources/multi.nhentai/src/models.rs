@@ -1,5 +1,11 @@
 use crate::localization_cn::*;
-use crate::settings::{self, TitlePreference};
+use crate::localization_ja::*;
+use crate::localization_zh_hant::{ZH_HANS, ZH_HANT};
+use crate::packed::Table;
+use crate::query::Namespace;
+use crate::settings::{self, TagDisplay, TitlePreference};
+use crate::strings::{self, Strings};
+use crate::{overrides, translation_db};
 use aidoku::{
 	ContentRating, Manga, MangaStatus, UpdateStrategy, Viewer,
 	alloc::{
@@ -11,82 +17,254 @@ use aidoku::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-fn get_tag_map(lang: &str) -> Option<&'static phf::Map<&'static str, &'static str>> {
-	match lang {
-		"chinese" => Some(&CN_TAG),
-		_ => None,
-	}
+/// A localization table along with how its names are shown
+#[derive(Clone, Copy)]
+struct NameMap {
+	map: Table,
+	namespace: Namespace,
+	/// Whether the downloaded database is consulted before `map`
+	runtime: bool,
+	/// Whether Chinese names are converted to Traditional
+	traditional: bool,
+	display: TagDisplay,
 }
 
-fn get_parody_map(lang: &str) -> Option<&'static phf::Map<&'static str, &'static str>> {
-	match lang {
-		"chinese" => Some(&CN_PARODY),
-		_ => None,
-	}
+fn name_map(
+	lang: &str,
+	display: TagDisplay,
+	namespace: Namespace,
+	cn: Table,
+	ja: Table,
+) -> Option<NameMap> {
+	let (map, runtime, traditional) = match lang {
+		"chinese" => (cn, true, false),
+		"traditional-chinese" => (cn, true, true),
+		"japanese" => (ja, false, false),
+		_ => return None,
+	};
+	Some(NameMap {
+		map,
+		namespace,
+		runtime,
+		traditional,
+		display,
+	})
 }
 
-fn get_character_map(lang: &str) -> Option<&'static phf::Map<&'static str, &'static str>> {
-	match lang {
-		"chinese" => Some(&CN_CHARACTER),
-		_ => None,
-	}
+fn get_tag_map(lang: &str, display: TagDisplay) -> Option<NameMap> {
+	name_map(lang, display, Namespace::Tag, CN_TAG, JA_TAG)
+}
+
+fn get_parody_map(lang: &str, display: TagDisplay) -> Option<NameMap> {
+	name_map(lang, display, Namespace::Parody, CN_PARODY, JA_PARODY)
+}
+
+fn get_character_map(lang: &str, display: TagDisplay) -> Option<NameMap> {
+	name_map(
+		lang,
+		display,
+		Namespace::Character,
+		CN_CHARACTER,
+		JA_CHARACTER,
+	)
 }
 
-fn get_group_map(lang: &str) -> Option<&'static phf::Map<&'static str, &'static str>> {
-	match lang {
-		"chinese" => Some(&CN_GROUP),
-		_ => None,
+fn get_group_map(lang: &str, display: TagDisplay) -> Option<NameMap> {
+	name_map(lang, display, Namespace::Group, CN_GROUP, JA_GROUP)
+}
+
+fn get_artist_map(lang: &str, display: TagDisplay) -> Option<NameMap> {
+	name_map(lang, display, Namespace::Artist, CN_ARTIST, JA_ARTIST)
+}
+
+/// Convert Simplified Chinese characters to Traditional
+fn to_traditional(text: &str) -> String {
+	text.chars()
+		.map(|c| ZH_HANT.get(&c).copied().unwrap_or(c))
+		.collect()
+}
+
+/// Whether the text contains kana, so is Japanese rather than Chinese
+fn has_kana(text: &str) -> bool {
+	text.chars().any(|c| ('\u{3040}'..='\u{30ff}').contains(&c))
+}
+
+/// Fold Traditional Chinese characters to Simplified, the form the reverse maps are keyed by
+pub fn to_simplified(text: &str) -> String {
+	text.chars()
+		.map(|c| ZH_HANS.get(&c).copied().unwrap_or(c))
+		.collect()
+}
+
+/// Translate a name, checking the overrides and downloaded database before
+/// binary searching the packed table
+#[inline]
+fn translate_name(name: &str, map: Option<NameMap>) -> String {
+	let Some(NameMap {
+		map,
+		namespace,
+		runtime,
+		traditional,
+		display,
+	}) = map
+	else {
+		return name.to_string();
+	};
+	let lower = name.to_lowercase();
+	// overrides are shown exactly as the user wrote them
+	let localized = overrides::translate(namespace, &lower).or_else(|| {
+		// Japanese names would be rewritten by the conversion, e.g. 広 to 廣
+		let (localized, japanese) = runtime
+			.then(|| translation_db::translate(namespace, &lower))
+			.flatten()
+			.map(|localized| {
+				let japanese = has_kana(&localized);
+				(localized, japanese)
+			})
+			.or_else(|| {
+				let entry = map.entry(&lower)?;
+				Some((entry.value.into(), entry.japanese))
+			})?;
+		Some(if traditional && !japanese {
+			to_traditional(&localized)
+		} else {
+			localized
+		})
+	});
+	// many names are kept in English, which a bilingual label would only repeat
+	let Some(localized) = localized.filter(|localized| localized.to_lowercase() != lower) else {
+		return name.to_string();
+	};
+	match display {
+		TagDisplay::Localized => localized,
+		TagDisplay::LocalizedFirst => format!("{localized} ({name})"),
+		TagDisplay::EnglishFirst => format!("{name} / {localized}"),
 	}
 }
 
-fn get_artist_map(lang: &str) -> Option<&'static phf::Map<&'static str, &'static str>> {
-	match lang {
-		"chinese" => Some(&CN_ARTIST),
-		_ => None,
+/// Split a bilingual label into its localized and English parts
+fn split_bilingual(label: &str) -> Option<(&str, &str)> {
+	if let Some(rest) = label.strip_suffix(')')
+		&& let Some((localized, english)) = rest.split_once(" (")
+	{
+		return Some((localized, english));
 	}
+	label
+		.split_once(" / ")
+		.map(|(english, localized)| (localized, english))
 }
 
-/// Translate a name using PHF map (O(1) lookup)
-#[inline]
-fn translate_name(name: &str, map: Option<&phf::Map<&'static str, &'static str>>) -> String {
-	match map {
-		Some(m) => {
-			let lower = name.to_lowercase();
-			m.get(lower.as_str())
-				.map(|s| (*s).to_string())
-				.unwrap_or_else(|| name.to_string())
+/// A reverse localization table, layered under the user overrides and, for
+/// Chinese, the downloaded database
+#[derive(Clone, Copy)]
+struct ReverseMap {
+	map: Table,
+	namespace: Namespace,
+	runtime: bool,
+}
+
+fn get_reverse_map(lang: &str, namespace: Namespace) -> Option<ReverseMap> {
+	let map: Table = match (lang, namespace) {
+		("chinese" | "traditional-chinese", Namespace::Tag) => CN_TAG_REVERSE,
+		("chinese" | "traditional-chinese", Namespace::Parody) => CN_PARODY_REVERSE,
+		("chinese" | "traditional-chinese", Namespace::Character) => CN_CHARACTER_REVERSE,
+		("chinese" | "traditional-chinese", Namespace::Group) => CN_GROUP_REVERSE,
+		("chinese" | "traditional-chinese", Namespace::Artist) => CN_ARTIST_REVERSE,
+		("japanese", Namespace::Tag) => JA_TAG_REVERSE,
+		("japanese", Namespace::Parody) => JA_PARODY_REVERSE,
+		("japanese", Namespace::Character) => JA_CHARACTER_REVERSE,
+		("japanese", Namespace::Group) => JA_GROUP_REVERSE,
+		("japanese", Namespace::Artist) => JA_ARTIST_REVERSE,
+		_ => return None,
+	};
+	Some(ReverseMap {
+		map,
+		namespace,
+		runtime: lang != "japanese",
+	})
+}
+
+/// Look up the English name in a reverse map, accepting Simplified or Traditional input
+/// as well as bilingual labels
+fn lookup_reverse(map: ReverseMap, query: &str) -> Option<String> {
+	let lookup = |name: &str| {
+		let key = to_simplified(&name.trim().to_lowercase());
+		overrides::reverse(map.namespace, &key)
+			.or_else(|| {
+				map.runtime
+					.then(|| translation_db::reverse(map.namespace, &key))
+					.flatten()
+			})
+			.or_else(|| map.map.get(key.as_str()).map(String::from))
+	};
+	let query = query.trim();
+	if let Some((localized, english)) = split_bilingual(query)
+		&& let Some(name) = lookup(localized)
+	{
+		let english = english.trim();
+		if name.eq_ignore_ascii_case(english) {
+			return Some(name);
 		}
-		None => name.to_string(),
+		// names may contain the separators themselves, otherwise the halves
+		// disagree and the English one is what nhentai knows
+		return lookup(query).or_else(|| Some(english.to_lowercase()));
 	}
+	lookup(query)
 }
 
 /// Reverse translate a localized tag to English (for search)
 pub fn reverse_translate_tag(query: &str) -> String {
 	let lang = settings::get_tag_language();
-	if lang == "english" {
-		return query.to_string();
-	}
+	get_reverse_map(&lang, Namespace::Tag)
+		.and_then(|map| lookup_reverse(map, query))
+		.unwrap_or_else(|| query.to_string())
+}
 
-	let lower = query.to_lowercase();
-	match lang.as_str() {
-		"chinese" => CN_TAG_REVERSE
-			.get(lower.as_str())
-			.map(|s| (*s).to_string())
-			.unwrap_or_else(|| query.to_string()),
-		_ => query.to_string(),
-	}
+/// Look up the English name for a localized name in the given namespace
+pub fn reverse_lookup(query: &str, namespace: Namespace) -> Option<String> {
+	let tag_lang = settings::get_tag_language();
+	let metadata_lang = settings::get_metadata_language();
+	let map = get_reverse_map(&metadata_lang, namespace)
+		.or_else(|| get_reverse_map(&tag_lang, namespace))?;
+	lookup_reverse(map, query)
+}
+
+/// Reverse translate a localized name in the given namespace to English (for search)
+pub fn reverse_translate_name(query: &str, namespace: Namespace) -> String {
+	reverse_lookup(query, namespace).unwrap_or_else(|| query.trim().to_string())
 }
 
 /// Translate an English tag to localized version (for display)
-pub fn translate_tag(tag: &str, lang: &str) -> String {
+pub fn translate_tag(tag: &str, lang: &str, display: TagDisplay) -> String {
 	if lang == "english" {
 		return tag.to_string();
 	}
 
-	let map = get_tag_map(lang);
+	let map = get_tag_map(lang, display);
 	translate_name(tag, map)
 }
 
+/// nhentai gallery categories
+pub const CATEGORIES: [&str; 9] = [
+	"doujinshi",
+	"manga",
+	"artistcg",
+	"gamecg",
+	"western",
+	"non-h",
+	"imageset",
+	"cosplay",
+	"misc",
+];
+
+fn category_title<'a>(name: &'a str, strings: &'static Strings) -> &'a str {
+	CATEGORIES
+		.iter()
+		.position(|id| *id == name)
+		.map(|index| strings.category_options[index])
+		.unwrap_or(name)
+}
+
 pub fn extension_from_type(t: &str) -> &str {
 	match t {
 		"j" => "jpg",
@@ -146,6 +324,28 @@ pub struct NHentaiSearchResponse {
 	pub per_page: i32,
 }
 
+impl NHentaiTitle {
+	/// Find the first of the given lowercase keywords contained in any of the titles
+	pub fn find_keyword<'a>(&self, keywords: &'a [String]) -> Option<&'a str> {
+		if keywords.is_empty() {
+			return None;
+		}
+		let titles = [
+			Some(&self.english),
+			self.japanese.as_ref(),
+			Some(&self.pretty),
+		]
+		.into_iter()
+		.flatten()
+		.map(|title| title.to_lowercase())
+		.collect::<Vec<_>>();
+		keywords
+			.iter()
+			.find(|keyword| titles.iter().any(|title| title.contains(keyword.as_str())))
+			.map(|keyword| keyword.as_str())
+	}
+}
+
 impl NHentaiGallery {
 	pub fn id_str(&self) -> String {
 		match &self.id {
@@ -156,28 +356,60 @@ impl NHentaiGallery {
 	}
 }
 
+/// Tags used by more galleries than this are assumed to be familiar, which
+/// leaves the glossary to niche tags like "stomach deformation" or "x-ray"
+const GLOSSARY_MAX_COUNT: i32 = 5_000;
+/// Most tags explained in one description
+const GLOSSARY_LENGTH: usize = 5;
+
 impl From<NHentaiGallery> for Manga {
 	fn from(value: NHentaiGallery) -> Self {
 		let tag_lang = settings::get_tag_language();
 		let metadata_lang = settings::get_metadata_language();
 		let title_preference = settings::get_title_preference();
+		let strings = strings::get();
 
 		let mut tags = Vec::new();
 		let mut artists = Vec::new();
 		let mut groups = Vec::new();
 		let mut parodies = Vec::new();
 		let mut characters = Vec::new();
+		let mut categories = Vec::new();
+		let mut glossary = Vec::new();
 
-		let tag_map = get_tag_map(&tag_lang);
-		let artist_map = get_artist_map(&metadata_lang).or_else(|| get_artist_map(&tag_lang));
-		let group_map = get_group_map(&metadata_lang).or_else(|| get_group_map(&tag_lang));
-		let parody_map = get_parody_map(&metadata_lang).or_else(|| get_parody_map(&tag_lang));
-		let character_map = get_character_map(&metadata_lang).or_else(|| get_character_map(&tag_lang));
+		// introductions come from the downloaded database, which is Chinese
+		let glossary_lang = match tag_lang.as_str() {
+			"chinese" | "traditional-chinese" if settings::get_tag_glossary() => Some(&tag_lang),
+			_ => None,
+		};
+		let display = settings::get_tag_display();
+		let tag_map = get_tag_map(&tag_lang, display);
+		let artist_map =
+			get_artist_map(&metadata_lang, display).or_else(|| get_artist_map(&tag_lang, display));
+		let group_map =
+			get_group_map(&metadata_lang, display).or_else(|| get_group_map(&tag_lang, display));
+		let parody_map =
+			get_parody_map(&metadata_lang, display).or_else(|| get_parody_map(&tag_lang, display));
+		let character_map = get_character_map(&metadata_lang, display)
+			.or_else(|| get_character_map(&tag_lang, display));
 
 		for tag in &value.tags {
 			match tag.r#type.as_str() {
 				"tag" => {
 					let name = translate_name(&tag.name, tag_map);
+					let intro = glossary_lang
+						.filter(|_| tag.count < GLOSSARY_MAX_COUNT)
+						.and_then(|lang| {
+							let intro = translation_db::intro(&tag.name.to_lowercase())?;
+							Some(if lang == "traditional-chinese" {
+								to_traditional(&intro)
+							} else {
+								intro
+							})
+						});
+					if let Some(intro) = intro {
+						glossary.push((name.clone(), intro, tag.count));
+					}
 					tags.push((name, tag.count));
 				}
 				"artist" => {
@@ -198,6 +430,9 @@ impl From<NHentaiGallery> for Manga {
 					let name = translate_name(&tag.name, character_map);
 					characters.push((name, tag.count));
 				}
+				"category" => {
+					categories.push(category_title(&tag.name, strings).to_string());
+				}
 				_ => {}
 			}
 		}
@@ -208,6 +443,9 @@ impl From<NHentaiGallery> for Manga {
 		groups.sort_by(|a, b| b.1.cmp(&a.1));
 		parodies.sort_by(|a, b| b.1.cmp(&a.1));
 		characters.sort_by(|a, b| b.1.cmp(&a.1));
+		// least common tags first, as they need explaining most
+		glossary.sort_by_key(|(_, _, count)| *count);
+		glossary.truncate(GLOSSARY_LENGTH);
 
 		// Extract names
 		let tags: Vec<_> = tags.into_iter().map(|(name, _)| name).collect();
@@ -219,15 +457,41 @@ impl From<NHentaiGallery> for Manga {
 		let description = {
 			let mut info_parts = Vec::new();
 			info_parts.push(format!("#{}", value.id_str()));
+			if !categories.is_empty() {
+				info_parts.push(format!(
+					"{}: {}",
+					strings.category_label,
+					categories.join(", ")
+				));
+			}
 			if !parodies.is_empty() {
-				info_parts.push(format!("Parodies: {}", parodies.join(", ")));
+				info_parts.push(format!(
+					"{}: {}",
+					strings.parodies_label,
+					parodies.join(", ")
+				));
 			}
 			if !characters.is_empty() {
-				info_parts.push(format!("Characters: {}", characters.join(", ")));
+				info_parts.push(format!(
+					"{}: {}",
+					strings.characters_label,
+					characters.join(", ")
+				));
 			}
-			info_parts.push(format!("Pages: {}", value.num_pages));
+			info_parts.push(format!("{}: {}", strings.pages_label, value.num_pages));
 			if value.num_favorites > 0 {
-				info_parts.push(format!("Favorited by: {}", value.num_favorites));
+				info_parts.push(format!(
+					"{}: {}",
+					strings.favorited_by_label, value.num_favorites
+				));
+			}
+			if !glossary.is_empty() {
+				info_parts.push(format!("{}:", strings.glossary_label));
+				info_parts.extend(
+					glossary
+						.into_iter()
+						.map(|(name, intro, _)| format!("{name}: {intro}")),
+				);
 			}
 			info_parts.join("  \n")
 		};
@@ -272,3 +536,37 @@ impl From<NHentaiGallery> for Manga {
 		}
 	}
 }
+
+#[cfg(all(test, any(feature = "cn-tags", feature = "cn-names")))]
+mod tests {
+	use super::*;
+
+	#[cfg(feature = "cn-tags")]
+	#[test]
+	fn bilingual_labels_round_trip() {
+		let map = |display| get_tag_map("chinese", display);
+		assert_eq!(translate_name("anal", map(TagDisplay::Localized)), "爆肛");
+		for display in [TagDisplay::LocalizedFirst, TagDisplay::EnglishFirst] {
+			let label = translate_name("anal", map(display));
+			assert_eq!(split_bilingual(&label), Some(("爆肛", "anal")));
+			let reverse = get_reverse_map("chinese", Namespace::Tag).unwrap();
+			assert_eq!(lookup_reverse(reverse, &label).as_deref(), Some("anal"));
+		}
+	}
+
+	#[cfg(feature = "cn-tags")]
+	#[test]
+	fn untranslated_names_are_not_repeated() {
+		for display in [TagDisplay::LocalizedFirst, TagDisplay::EnglishFirst] {
+			assert_eq!(translate_name("3d", get_tag_map("chinese", display)), "3d");
+		}
+	}
+
+	#[cfg(feature = "cn-names")]
+	#[test]
+	fn traditional_skips_japanese_names() {
+		let map = get_artist_map("traditional-chinese", TagDisplay::Localized);
+		assert_eq!(translate_name("adachi takumi", map), "安達拓實");
+		assert_eq!(translate_name("aida takanobu", map), "会田孝信");
+	}
+}
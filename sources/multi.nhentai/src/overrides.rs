@@ -0,0 +1,74 @@
+use crate::{cache::Cache, models::to_simplified, query::Namespace, settings};
+use aidoku::alloc::{String, Vec};
+
+/// A user-defined translation, e.g. `anal=肛交` or `artist:name=本地名`
+///
+/// Entries without a namespace apply to names of any type.
+struct Override {
+	namespace: Option<Namespace>,
+	english: String,
+	localized: String,
+	/// Localized name folded like the reverse maps
+	key: String,
+}
+
+impl Override {
+	fn parse(entry: &str) -> Option<Self> {
+		let (name, localized) = entry.split_once('=')?;
+		let (namespace, english) = name
+			.split_once(':')
+			.and_then(|(prefix, english)| Some((Namespace::parse(prefix.trim())?, english)))
+			.map_or((None, name), |(namespace, english)| {
+				(Some(namespace), english)
+			});
+		let english = english.trim().to_lowercase();
+		let localized = localized.trim();
+		if english.is_empty() || localized.is_empty() {
+			return None;
+		}
+		Some(Self {
+			namespace,
+			english,
+			localized: localized.into(),
+			key: to_simplified(&localized.to_lowercase()),
+		})
+	}
+
+	fn applies_to(&self, namespace: Namespace) -> bool {
+		self.namespace.is_none_or(|own| own == namespace)
+	}
+}
+
+static CACHE: Cache<Vec<Override>> = Cache::new();
+
+fn load() -> Vec<Override> {
+	settings::get_translation_overrides()
+		.iter()
+		.filter_map(|entry| Override::parse(entry))
+		.collect()
+}
+
+/// Reload the overrides after the setting changed
+pub fn invalidate() {
+	CACHE.invalidate();
+}
+
+/// Localized name for a lowercase English name
+pub fn translate(namespace: Namespace, name: &str) -> Option<String> {
+	CACHE.with(load, |overrides| {
+		overrides
+			.iter()
+			.find(|o| o.applies_to(namespace) && o.english == name)
+			.map(|o| o.localized.clone())
+	})
+}
+
+/// English name for a localized name folded with `to_simplified`
+pub fn reverse(namespace: Namespace, key: &str) -> Option<String> {
+	CACHE.with(load, |overrides| {
+		overrides
+			.iter()
+			.find(|o| o.applies_to(namespace) && o.key == key)
+			.map(|o| o.english.clone())
+	})
+}
@@ -0,0 +1,293 @@
+use crate::cache::Cache;
+use aidoku::alloc::{Box, Vec};
+
+/// Shortest copy in the compressed stream
+const MIN_MATCH: usize = 3;
+
+/// Localization tables packed by `scripts/pack_localization.py`
+///
+/// The blob is only decompressed the first time one of its tables is used.
+pub struct Pack {
+	blob: &'static [u8],
+	decoded: Cache<&'static Decoded>,
+}
+
+/// One map stored in a `Pack`
+#[derive(Clone, Copy)]
+pub struct Table {
+	pack: &'static Pack,
+	index: usize,
+	/// Only the entries written in Japanese script are visible
+	japanese: bool,
+}
+
+/// A localized name found in a `Table`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry {
+	pub value: &'static str,
+	/// Written in Japanese script, so left alone by the Traditional conversion
+	pub japanese: bool,
+}
+
+#[derive(Default)]
+struct Decoded {
+	strings: Vec<&'static str>,
+	/// (key, value) indices into `strings`, sorted by key, with the value
+	/// shifted left by one to hold the Japanese script flag
+	tables: Vec<Vec<(u32, u32)>>,
+}
+
+struct Reader<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	fn varint(&mut self) -> Option<usize> {
+		let mut value = 0;
+		for shift in (0..usize::BITS).step_by(7) {
+			let (&byte, rest) = self.data.split_first()?;
+			self.data = rest;
+			value |= ((byte & 0x7f) as usize) << shift;
+			if byte < 0x80 {
+				return Some(value);
+			}
+		}
+		None
+	}
+
+	fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+		let (bytes, rest) = self.data.split_at_checked(len)?;
+		self.data = rest;
+		Some(bytes)
+	}
+}
+
+/// Undo the LZ77 compression described in `pack_localization.py`
+fn decompress(blob: &[u8]) -> Option<Vec<u8>> {
+	let (size, mut input) = blob.split_first_chunk::<4>()?;
+	let size = u32::from_le_bytes(*size) as usize;
+	let mut output = Vec::with_capacity(size);
+	while let Some((&token, rest)) = input.split_first() {
+		if token < 0x80 {
+			let (literal, rest) = rest.split_at_checked(token as usize + 1)?;
+			output.extend_from_slice(literal);
+			input = rest;
+		} else {
+			let (distance, rest) = rest.split_first_chunk::<2>()?;
+			let distance = u16::from_le_bytes(*distance) as usize;
+			if distance == 0 {
+				return None;
+			}
+			let start = output.len().checked_sub(distance)?;
+			// copies may overlap the bytes they produce
+			for i in start..start + (token & 0x7f) as usize + MIN_MATCH {
+				output.push(output[i]);
+			}
+			input = rest;
+		}
+	}
+	(output.len() == size).then_some(output)
+}
+
+impl Decoded {
+	fn parse(data: &[u8]) -> Option<Self> {
+		let mut reader = Reader { data };
+
+		// the pool only stores what each string doesn't share with the one
+		// before it, so rebuild the strings into one buffer kept for good
+		let count = reader.varint()?;
+		let mut buffer = Vec::new();
+		let mut ranges = Vec::with_capacity(count);
+		let mut previous = 0..0;
+		for _ in 0..count {
+			let shared = reader.varint()?;
+			let len = reader.varint()?;
+			if shared > previous.len() {
+				return None;
+			}
+			let start = buffer.len();
+			buffer.extend_from_within(previous.start..previous.start + shared);
+			buffer.extend_from_slice(reader.bytes(len)?);
+			previous = start..buffer.len();
+			ranges.push(previous.clone());
+		}
+		let buffer: &'static [u8] = Vec::leak(buffer);
+		let strings = ranges
+			.into_iter()
+			.map(|range| core::str::from_utf8(&buffer[range]).ok())
+			.collect::<Option<Vec<_>>>()?;
+
+		let count = reader.varint()?;
+		let mut tables = Vec::with_capacity(count);
+		for _ in 0..count {
+			let len = reader.varint()?;
+			let mut pairs = Vec::with_capacity(len);
+			let mut key = 0;
+			for _ in 0..len {
+				key += reader.varint()?;
+				let value = reader.varint()?;
+				if key >= strings.len() || value >> 1 >= strings.len() {
+					return None;
+				}
+				pairs.push((key as u32, value as u32));
+			}
+			tables.push(pairs);
+		}
+
+		Some(Self { strings, tables })
+	}
+}
+
+impl Pack {
+	/// An empty `blob` gives empty tables, for packs whose feature is disabled
+	pub const fn new(blob: &'static [u8]) -> Self {
+		Self {
+			blob,
+			decoded: Cache::new(),
+		}
+	}
+
+	fn decoded(&'static self) -> &'static Decoded {
+		let load = || {
+			let decoded = decompress(self.blob).and_then(|data| Decoded::parse(&data));
+			// a broken blob would otherwise only show up as missing translations
+			debug_assert!(
+				decoded.is_some() || self.blob.is_empty(),
+				"corrupt localization pack"
+			);
+			&*Box::leak(Box::new(decoded.unwrap_or_default()))
+		};
+		self.decoded.with(load, |decoded| *decoded)
+	}
+}
+
+impl Table {
+	pub const fn new(pack: &'static Pack, index: usize) -> Self {
+		Self {
+			pack,
+			index,
+			japanese: false,
+		}
+	}
+
+	/// A view of a table keeping only the entries written in Japanese script,
+	/// so Japanese names can share the Chinese pack
+	pub const fn japanese(pack: &'static Pack, index: usize) -> Self {
+		Self {
+			pack,
+			index,
+			japanese: true,
+		}
+	}
+
+	pub fn get(&self, key: &str) -> Option<&'static str> {
+		self.entry(key).map(|entry| entry.value)
+	}
+
+	pub fn entry(&self, key: &str) -> Option<Entry> {
+		let decoded = self.pack.decoded();
+		let pairs = decoded.tables.get(self.index)?;
+		let found = pairs
+			.binary_search_by(|(k, _)| decoded.strings[*k as usize].cmp(key))
+			.ok()?;
+		let value = pairs[found].1;
+		let entry = Entry {
+			value: decoded.strings[(value >> 1) as usize],
+			japanese: value & 1 == 1,
+		};
+		(entry.japanese || !self.japanese).then_some(entry)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Table;
+	use crate::{localization_cn::*, localization_ja::*};
+
+	#[allow(dead_code)]
+	fn assert_decoded(tables: &[Table]) {
+		for table in tables {
+			let decoded = table.pack.decoded();
+			assert!(!decoded.tables[table.index].is_empty());
+		}
+	}
+
+	#[test]
+	fn decodes_enabled_packs() {
+		#[cfg(feature = "cn-tags")]
+		assert_decoded(&[CN_TAG, CN_PARODY, CN_TAG_REVERSE, CN_PARODY_REVERSE]);
+		#[cfg(feature = "cn-names")]
+		assert_decoded(&[
+			CN_CHARACTER,
+			CN_GROUP,
+			CN_ARTIST,
+			CN_CHARACTER_REVERSE,
+			CN_GROUP_REVERSE,
+			CN_ARTIST_REVERSE,
+		]);
+		#[cfg(feature = "ja-tags")]
+		assert_decoded(&[JA_TAG, JA_PARODY, JA_TAG_REVERSE, JA_PARODY_REVERSE]);
+		#[cfg(feature = "ja-names")]
+		assert_decoded(&[
+			JA_CHARACTER,
+			JA_GROUP,
+			JA_ARTIST,
+			JA_CHARACTER_REVERSE,
+			JA_GROUP_REVERSE,
+			JA_ARTIST_REVERSE,
+		]);
+	}
+
+	#[test]
+	fn disabled_packs_are_empty() {
+		#[cfg(not(feature = "cn-tags"))]
+		assert_eq!(CN_TAG.get("anal"), None);
+		#[cfg(not(feature = "cn-names"))]
+		assert_eq!(CN_CHARACTER.get("asuka langley soryu"), None);
+		#[cfg(not(feature = "ja-tags"))]
+		assert_eq!(JA_TAG.get("anal"), None);
+		#[cfg(not(feature = "ja-names"))]
+		assert_eq!(JA_ARTIST.get("garland"), None);
+	}
+
+	#[test]
+	fn forward_lookups() {
+		#[cfg(feature = "cn-tags")]
+		{
+			assert_eq!(CN_TAG.get("anal"), Some("爆肛"));
+			assert_eq!(CN_PARODY.get("kantai collection"), Some("舰队Collection"));
+			assert_eq!(CN_TAG.get("not a tag"), None);
+		}
+		#[cfg(feature = "cn-names")]
+		assert_eq!(
+			CN_CHARACTER.get("asuka langley soryu"),
+			Some("惣流·明日香·兰格雷")
+		);
+		#[cfg(feature = "ja-tags")]
+		assert_eq!(JA_TAG.get("anal"), Some("アナル"));
+		#[cfg(feature = "ja-names")]
+		{
+			assert_eq!(JA_ARTIST.get("garland"), Some("があらんど"));
+			// in the Chinese pack, but not in Japanese script
+			assert_eq!(JA_ARTIST.get("adachi takumi"), None);
+		}
+	}
+
+	#[test]
+	fn reverse_lookups() {
+		#[cfg(feature = "cn-tags")]
+		{
+			assert_eq!(CN_TAG_REVERSE.get("爆肛"), Some("anal"));
+			assert_eq!(CN_PARODY_REVERSE.get("东方project"), Some("touhou project"));
+		}
+		#[cfg(feature = "cn-names")]
+		assert_eq!(
+			CN_CHARACTER_REVERSE.get("惣流·明日香·兰格雷"),
+			Some("asuka langley soryu")
+		);
+		#[cfg(feature = "ja-tags")]
+		assert_eq!(JA_TAG_REVERSE.get("アナル"), Some("anal"));
+		#[cfg(feature = "ja-names")]
+		assert_eq!(JA_ARTIST_REVERSE.get("があらんど"), Some("garland"));
+	}
+}
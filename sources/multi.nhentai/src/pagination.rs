@@ -0,0 +1,108 @@
+use aidoku::alloc::{String, Vec};
+
+/// Number of recent queries to remember page offsets for
+const MAX_QUERIES: usize = 8;
+
+/// Maps Aidoku page numbers to the nhentai page each one starts at
+///
+/// Backfilling a filtered page consumes later nhentai pages, so every page
+/// after it is shifted. Offsets are kept per query, keyed by its first page url.
+#[derive(Default)]
+pub struct PageMap {
+	/// Recorded (page, start) pairs of each query, sorted by page
+	queries: Vec<(String, Vec<(i32, i32)>)>,
+}
+
+impl PageMap {
+	/// The nhentai page to start from for an Aidoku page
+	///
+	/// Pages that were never recorded continue from the last recorded page
+	/// before them, one nhentai page each.
+	pub fn start(&self, key: &str, page: i32) -> i32 {
+		if page <= 1 {
+			return 1;
+		}
+		let (recorded, start) = self
+			.queries
+			.iter()
+			.find(|(query, _)| query == key)
+			.and_then(|(_, starts)| starts.iter().rev().find(|(p, _)| *p <= page))
+			.copied()
+			.unwrap_or((1, 1));
+		start + (page - recorded)
+	}
+
+	/// Remember the nhentai page an Aidoku page starts at
+	///
+	/// Later pages of the query are forgotten, as they followed the old start.
+	pub fn record(&mut self, key: &str, page: i32, start: i32) {
+		if page < 1 {
+			return;
+		}
+
+		let mut starts = match self.queries.iter().position(|(query, _)| query == key) {
+			Some(position) => self.queries.remove(position).1,
+			None => Vec::new(),
+		};
+		starts.retain(|(p, _)| *p < page);
+		starts.push((page, start));
+
+		if self.queries.len() >= MAX_QUERIES {
+			self.queries.remove(0);
+		}
+		self.queries.push((key.into(), starts));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku::prelude::*;
+
+	#[test]
+	fn unrecorded_queries_start_on_the_same_page() {
+		let pages = PageMap::default();
+		assert_eq!(pages.start("a", 0), 1);
+		assert_eq!(pages.start("a", 1), 1);
+		assert_eq!(pages.start("a", 5), 5);
+	}
+
+	#[test]
+	fn unrecorded_pages_continue_from_the_last_recorded_one() {
+		let mut pages = PageMap::default();
+		pages.record("a", 2, 4);
+		assert_eq!(pages.start("a", 1), 1);
+		assert_eq!(pages.start("a", 2), 4);
+		assert_eq!(pages.start("a", 3), 5);
+
+		pages.record("a", 3, 7);
+		assert_eq!(pages.start("a", 2), 4);
+		assert_eq!(pages.start("a", 5), 9);
+		assert_eq!(pages.start("b", 3), 3);
+	}
+
+	#[test]
+	fn recording_a_page_again_drops_later_ones() {
+		let mut pages = PageMap::default();
+		pages.record("a", 2, 4);
+		pages.record("a", 3, 7);
+		pages.record("a", 2, 3);
+		assert_eq!(pages.start("a", 2), 3);
+		assert_eq!(pages.start("a", 3), 4);
+	}
+
+	#[test]
+	fn least_recently_recorded_queries_are_evicted() {
+		let mut pages = PageMap::default();
+		pages.record("a", 2, 4);
+		pages.record("b", 2, 4);
+		for i in 0..MAX_QUERIES - 2 {
+			pages.record(&format!("query {i}"), 2, 4);
+		}
+		// recording moves a query to the back
+		pages.record("a", 3, 6);
+		pages.record("c", 2, 4);
+		assert_eq!(pages.start("a", 3), 6);
+		assert_eq!(pages.start("b", 2), 2);
+	}
+}
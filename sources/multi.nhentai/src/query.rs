@@ -0,0 +1,544 @@
+use crate::API_URL;
+use aidoku::{
+	alloc::{String, Vec, string::ToString},
+	helpers::uri::encode_uri_component,
+	prelude::*,
+};
+use core::fmt::{self, Write};
+
+/// Tag namespaces understood by the nhentai search syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+	Tag,
+	Artist,
+	Group,
+	Parody,
+	Character,
+	Language,
+	Category,
+}
+
+impl Namespace {
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"tag" | "tags" => Some(Self::Tag),
+			"artist" | "artists" => Some(Self::Artist),
+			"group" | "groups" => Some(Self::Group),
+			"parody" | "parodies" => Some(Self::Parody),
+			"character" | "characters" => Some(Self::Character),
+			"language" | "languages" => Some(Self::Language),
+			"category" | "categories" => Some(Self::Category),
+			_ => None,
+		}
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Tag => "tag",
+			Self::Artist => "artist",
+			Self::Group => "group",
+			Self::Parody => "parody",
+			Self::Character => "character",
+			Self::Language => "language",
+			Self::Category => "category",
+		}
+	}
+}
+
+/// Numeric fields that accept comparisons, e.g. `pages:>20`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeField {
+	Pages,
+	Uploaded,
+}
+
+impl RangeField {
+	fn parse(value: &str) -> Option<Self> {
+		match value {
+			"pages" => Some(Self::Pages),
+			"uploaded" => Some(Self::Uploaded),
+			_ => None,
+		}
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Pages => "pages",
+			Self::Uploaded => "uploaded",
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+	Equal,
+	Greater,
+	GreaterOrEqual,
+	Less,
+	LessOrEqual,
+}
+
+impl Comparison {
+	/// Split a leading comparison operator off a range value
+	fn split(value: &str) -> (Self, &str) {
+		for (prefix, cmp) in [
+			(">=", Self::GreaterOrEqual),
+			("<=", Self::LessOrEqual),
+			(">", Self::Greater),
+			("<", Self::Less),
+			("=", Self::Equal),
+		] {
+			if let Some(rest) = value.strip_prefix(prefix) {
+				return (cmp, rest);
+			}
+		}
+		(Self::Equal, value)
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Equal => "",
+			Self::Greater => ">",
+			Self::GreaterOrEqual => ">=",
+			Self::Less => "<",
+			Self::LessOrEqual => "<=",
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+	/// Bare word matched against titles and tags
+	Keyword(String),
+	/// Quoted phrase matched as a whole
+	Phrase(String),
+	/// Namespaced tag, e.g. `artist:"name"`
+	Tag(Namespace, String),
+	/// Numeric comparison with an optional unit, e.g. `uploaded:<7d`
+	Range {
+		field: RangeField,
+		cmp: Comparison,
+		value: u32,
+		unit: Option<char>,
+	},
+}
+
+impl Term {
+	/// Build a term from a raw token, where `quoted` is the content of a
+	/// trailing `"..."` section (if any)
+	fn from_token(token: &str, quoted: Option<&str>) -> Option<Self> {
+		if let Some(quoted) = quoted {
+			return match Self::quote_namespace(token) {
+				Some(namespace) => Self::tag(namespace, quoted),
+				None => Self::phrase(quoted),
+			};
+		}
+
+		if let Some((prefix, value)) = token.split_once(':') {
+			if let Some(field) = RangeField::parse(prefix)
+				&& let Some(range) = Self::range(field, value)
+			{
+				return Some(range);
+			}
+			if let Some(namespace) = Namespace::parse(prefix) {
+				return Self::tag(namespace, value);
+			}
+		}
+
+		let keyword = token.replace('"', "");
+		(!keyword.is_empty()).then_some(Self::Keyword(keyword))
+	}
+
+	/// The namespace a token ending right before a quote names, as in `artist:"name"`
+	fn quote_namespace(token: &str) -> Option<Namespace> {
+		token.strip_suffix(':').and_then(Namespace::parse)
+	}
+
+	fn tag(namespace: Namespace, name: &str) -> Option<Self> {
+		let name = clean(name).to_lowercase();
+		(!name.is_empty()).then_some(Self::Tag(namespace, name))
+	}
+
+	fn phrase(phrase: &str) -> Option<Self> {
+		let phrase = clean(phrase);
+		(!phrase.is_empty()).then_some(Self::Phrase(phrase))
+	}
+
+	fn range(field: RangeField, value: &str) -> Option<Self> {
+		let (cmp, rest) = Comparison::split(value);
+		let digits = rest
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(rest.len());
+		let value = rest[..digits].parse().ok()?;
+		let mut suffix = rest[digits..].chars();
+		let unit = suffix.next();
+		if suffix.next().is_some() || unit.is_some_and(|c| !c.is_ascii_alphabetic()) {
+			return None;
+		}
+		Some(Self::Range {
+			field,
+			cmp,
+			value,
+			unit,
+		})
+	}
+}
+
+impl fmt::Display for Term {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Keyword(keyword) => f.write_str(keyword),
+			Self::Phrase(phrase) => write!(f, "\"{phrase}\""),
+			Self::Tag(namespace, name) => write!(f, "{}:\"{name}\"", namespace.as_str()),
+			Self::Range {
+				field,
+				cmp,
+				value,
+				unit,
+			} => {
+				write!(f, "{}:{}{value}", field.as_str(), cmp.as_str())?;
+				match unit {
+					Some(unit) => f.write_char(*unit),
+					None => Ok(()),
+				}
+			}
+		}
+	}
+}
+
+/// Collapse whitespace and drop double quotes, which nhentai has no way to escape
+fn clean(value: &str) -> String {
+	value
+		.split(|c: char| c.is_whitespace() || c == '"')
+		.filter(|part| !part.is_empty())
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Clause {
+	term: Term,
+	negated: bool,
+}
+
+/// Sort orders accepted by the search endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+	#[default]
+	Recent,
+	PopularToday,
+	PopularWeek,
+	Popular,
+}
+
+impl SortOrder {
+	/// Map an index of the sort filter to a sort order
+	pub fn from_index(index: i32) -> Self {
+		match index {
+			1 => Self::PopularToday,
+			2 => Self::PopularWeek,
+			3 => Self::Popular,
+			_ => Self::Recent,
+		}
+	}
+
+	/// Map a listing id from `source.json` to a sort order
+	pub fn from_listing(id: &str) -> Option<Self> {
+		match id {
+			"latest" => Some(Self::Recent),
+			"popular-today" => Some(Self::PopularToday),
+			"popular-week" => Some(Self::PopularWeek),
+			"popular" => Some(Self::Popular),
+			_ => None,
+		}
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Recent => "recent",
+			Self::PopularToday => "popular-today",
+			Self::PopularWeek => "popular-week",
+			Self::Popular => "popular",
+		}
+	}
+}
+
+/// A nhentai search query composed of deduplicated, correctly quoted terms
+///
+/// When the same term is both included and excluded, the exclusion wins.
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+	clauses: Vec<Clause>,
+}
+
+impl SearchQuery {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parse user-entered query syntax and add its terms
+	pub fn push_raw(&mut self, input: &str) {
+		let mut chars = input.chars().peekable();
+		while let Some(&c) = chars.peek() {
+			if c.is_whitespace() {
+				chars.next();
+				continue;
+			}
+
+			let negated = c == '-';
+			if negated {
+				chars.next();
+			}
+
+			let mut token = String::new();
+			let mut quoted = None;
+			while let Some(&c) = chars.peek() {
+				if c.is_whitespace() {
+					break;
+				}
+				chars.next();
+				if c == '"' {
+					quoted = Some(chars.by_ref().take_while(|&c| c != '"').collect::<String>());
+					break;
+				}
+				token.push(c);
+			}
+
+			// text before a quote that doesn't name a namespace is its own keyword
+			if quoted.is_some() && !token.is_empty() && Term::quote_namespace(&token).is_none() {
+				if let Some(term) = Term::from_token(&token, None) {
+					self.push(term, negated);
+				}
+				token.clear();
+			}
+			if let Some(term) = Term::from_token(&token, quoted.as_deref()) {
+				self.push(term, negated);
+			}
+		}
+	}
+
+	/// Require a namespaced tag
+	pub fn include(&mut self, namespace: Namespace, name: &str) {
+		if let Some(term) = Term::tag(namespace, name) {
+			self.push(term, false);
+		}
+	}
+
+	/// Exclude a namespaced tag
+	pub fn exclude(&mut self, namespace: Namespace, name: &str) {
+		if let Some(term) = Term::tag(namespace, name) {
+			self.push(term, true);
+		}
+	}
+
+	/// Whether a namespaced tag is required by the query
+	pub fn includes(&self, namespace: Namespace, name: &str) -> bool {
+		let Some(term) = Term::tag(namespace, name) else {
+			return false;
+		};
+		self.clauses
+			.iter()
+			.any(|clause| !clause.negated && clause.term == term)
+	}
+
+	/// Namespaced tags required by the query
+	pub fn included_tags(&self) -> impl Iterator<Item = (Namespace, &str)> {
+		self.clauses.iter().filter_map(|clause| match &clause.term {
+			Term::Tag(namespace, name) if !clause.negated => Some((*namespace, name.as_str())),
+			_ => None,
+		})
+	}
+
+	/// Require any one of `selected`, for namespaces where a gallery carries a
+	/// single value
+	///
+	/// nhentai joins terms with AND, so several values are expressed by
+	/// excluding every other value in `known`.
+	pub fn include_any<'a>(
+		&mut self,
+		namespace: Namespace,
+		selected: &[String],
+		known: impl Iterator<Item = &'a str>,
+	) {
+		if let [name] = selected {
+			self.include(namespace, name);
+		} else if selected.len() > 1 {
+			for name in known {
+				if !selected.iter().any(|selected| selected == name) {
+					self.exclude(namespace, name);
+				}
+			}
+		}
+	}
+
+	/// Add a numeric comparison, e.g. `pages:>20`
+	pub fn range(&mut self, field: RangeField, cmp: Comparison, value: u32, unit: Option<char>) {
+		self.push(
+			Term::Range {
+				field,
+				cmp,
+				value,
+				unit,
+			},
+			false,
+		);
+	}
+
+	/// Limit the page count to an inclusive range
+	///
+	/// nhentai only supports strict comparisons, so the bounds are widened by one.
+	pub fn pages(&mut self, min: Option<u32>, max: Option<u32>) {
+		if let Some(min) = min.filter(|min| *min > 1) {
+			self.range(RangeField::Pages, Comparison::Greater, min - 1, None);
+		}
+		if let Some(max) = max.filter(|max| *max >= 1) {
+			self.range(
+				RangeField::Pages,
+				Comparison::Less,
+				max.saturating_add(1),
+				None,
+			);
+		}
+	}
+
+	fn push(&mut self, term: Term, negated: bool) {
+		if let Some(existing) = self.clauses.iter_mut().find(|c| c.term == term) {
+			// an exclusion overrides an earlier inclusion of the same term
+			existing.negated |= negated;
+			return;
+		}
+		self.clauses.push(Clause { term, negated });
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.clauses.is_empty()
+	}
+
+	/// Build the search API url for a page of results
+	pub fn search_url(&self, page: i32, sort: SortOrder) -> String {
+		let query = if self.is_empty() {
+			" ".into()
+		} else {
+			self.to_string()
+		};
+		format!(
+			"{API_URL}/galleries/search?query={}&page={page}&sort={}",
+			encode_uri_component(query),
+			sort.as_str()
+		)
+	}
+}
+
+impl fmt::Display for SearchQuery {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, clause) in self.clauses.iter().enumerate() {
+			if i > 0 {
+				f.write_char(' ')?;
+			}
+			if clause.negated {
+				f.write_char('-')?;
+			}
+			write!(f, "{}", clause.term)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(input: &str) -> String {
+		let mut query = SearchQuery::new();
+		query.push_raw(input);
+		query.to_string()
+	}
+
+	#[test]
+	fn quotes_multi_word_names() {
+		let mut query = SearchQuery::new();
+		query.include(Namespace::Tag, "Big   Breasts");
+		query.include(Namespace::Artist, "\"shindol\"");
+		assert_eq!(query.to_string(), r#"tag:"big breasts" artist:"shindol""#);
+		assert_eq!(
+			parse(r#"artist:"some  artist" "full  color""#),
+			r#"artist:"some artist" "full color""#
+		);
+	}
+
+	#[test]
+	fn exclusion_wins_over_inclusion() {
+		let mut query = SearchQuery::new();
+		query.include(Namespace::Tag, "guro");
+		query.exclude(Namespace::Tag, "guro");
+		query.exclude(Namespace::Tag, "yaoi");
+		query.include(Namespace::Tag, "yaoi");
+		assert_eq!(query.to_string(), r#"-tag:"guro" -tag:"yaoi""#);
+		assert!(!query.includes(Namespace::Tag, "guro"));
+	}
+
+	#[test]
+	fn negated_namespaced_terms() {
+		assert_eq!(
+			parse(r#"-tag:"big breasts" -parody:touhou -language:english"#),
+			r#"-tag:"big breasts" -parody:"touhou" -language:"english""#
+		);
+		assert_eq!(parse("-unknown:value"), "-unknown:value");
+	}
+
+	#[test]
+	fn text_before_quotes_is_kept() {
+		assert_eq!(parse(r#"abc"def""#), r#"abc "def""#);
+		assert_eq!(parse(r#"-abc"def""#), r#"-abc -"def""#);
+		assert_eq!(parse(r#"unknown:"def""#), r#"unknown: "def""#);
+		assert_eq!(parse(r#"artist:"def""#), r#"artist:"def""#);
+	}
+
+	#[test]
+	fn range_comparisons() {
+		assert_eq!(parse("pages:>20"), "pages:>20");
+		assert_eq!(parse("pages:>=20"), "pages:>=20");
+		assert_eq!(parse("pages:<20"), "pages:<20");
+		assert_eq!(parse("pages:<=20"), "pages:<=20");
+		assert_eq!(parse("pages:=20 pages:20"), "pages:20");
+		assert_eq!(parse("uploaded:<7d"), "uploaded:<7d");
+		// not a valid range, so kept as a plain keyword
+		assert_eq!(parse("pages:>many"), "pages:>many");
+
+		let mut query = SearchQuery::new();
+		query.range(RangeField::Uploaded, Comparison::Greater, 30, Some('d'));
+		assert_eq!(query.to_string(), "uploaded:>30d");
+	}
+
+	#[test]
+	fn inclusive_page_bounds() {
+		let mut query = SearchQuery::new();
+		query.pages(Some(20), Some(40));
+		assert_eq!(query.to_string(), "pages:>19 pages:<41");
+
+		// a minimum of one page or no maximum adds nothing
+		let mut query = SearchQuery::new();
+		query.pages(Some(1), Some(0));
+		assert!(query.is_empty());
+		query.pages(None, Some(u32::MAX));
+		assert_eq!(query.to_string(), format!("pages:<{}", u32::MAX));
+	}
+
+	#[test]
+	fn search_url_encoding() {
+		let mut query = SearchQuery::new();
+		query.include(Namespace::Tag, "big breasts");
+		query.exclude(Namespace::Language, "english");
+		query.range(RangeField::Pages, Comparison::Greater, 20, None);
+		assert_eq!(
+			query.search_url(2, SortOrder::PopularWeek),
+			format!(
+				"{API_URL}/galleries/search?query=tag%3A%22big%20breasts%22%20-language%3A%22english%22%20pages%3A%3E20&page=2&sort=popular-week"
+			)
+		);
+		assert_eq!(
+			SearchQuery::new().search_url(1, SortOrder::Recent),
+			format!("{API_URL}/galleries/search?query=%20&page=1&sort=recent")
+		);
+	}
+}
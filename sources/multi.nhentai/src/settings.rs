@@ -1,14 +1,28 @@
 use aidoku::{
 	alloc::{string::String, vec::Vec},
-	imports::defaults::defaults_get,
+	imports::defaults::{DefaultValue, defaults_get, defaults_set},
 };
 const TITLE_PREFERENCE_KEY: &str = "titlePreference";
 const LANGUAGE_KEY: &str = "language";
 const BLOCKLIST_KEY: &str = "blocklist";
+const TITLE_BLOCKLIST_KEY: &str = "titleBlocklist";
+const SHOW_HIDDEN_KEY: &str = "showHidden";
+const MIN_PAGES_KEY: &str = "minPages";
+const MIN_FAVORITES_KEY: &str = "minFavorites";
+const ALLOWLIST_KEY: &str = "allowlist";
+const ALLOWLIST_MODE_KEY: &str = "allowlistMode";
 const LIST_VIEWER_KEY: &str = "isListView";
 const METADATA_LANGUAGE_KEY: &str = "metadataLanguage";
 const TAG_LANGUAGE_KEY: &str = "tagLanguage";
+const TAG_DISPLAY_KEY: &str = "tagDisplay";
 const FAVORITE_TAGS_KEY: &str = "favoriteTags";
+const IMPORT_LISTS_KEY: &str = "importLists";
+const EXPORT_LISTS_KEY: &str = "exportLists";
+const TRANSLATION_OVERRIDES_KEY: &str = "translationOverrides";
+const TRANSLATION_DB_URL_KEY: &str = "translationDbUrl";
+const TRANSLATION_DB_KEY: &str = "translationDb";
+const TRANSLATION_DB_STATUS_KEY: &str = "translationDbStatus";
+const TAG_GLOSSARY_KEY: &str = "tagGlossary";
 const SORT_TAGS_ALPHABETICALLY_KEY: &str = "sortTagsAlphabetically";
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -28,6 +42,48 @@ impl From<String> for TitlePreference {
 	}
 }
 
+/// How localized tags and names are labeled
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TagDisplay {
+	/// Only the localized name
+	#[default]
+	Localized,
+	/// `中文 (english)`
+	LocalizedFirst,
+	/// `english / 中文`
+	EnglishFirst,
+}
+
+impl From<String> for TagDisplay {
+	fn from(value: String) -> Self {
+		match value.as_str() {
+			"localized-english" => Self::LocalizedFirst,
+			"english-localized" => Self::EnglishFirst,
+			"localized" => Self::Localized,
+			_ => Self::Localized,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AllowlistMode {
+	/// Galleries need at least one allowlisted tag
+	#[default]
+	Any,
+	/// Galleries need every allowlisted tag
+	All,
+}
+
+impl From<String> for AllowlistMode {
+	fn from(value: String) -> Self {
+		match value.as_str() {
+			"all" => Self::All,
+			"any" => Self::Any,
+			_ => Self::Any,
+		}
+	}
+}
+
 pub fn get_title_preference() -> TitlePreference {
 	defaults_get::<String>(TITLE_PREFERENCE_KEY)
 		.map(TitlePreference::from)
@@ -51,6 +107,12 @@ pub fn get_tag_language() -> String {
 	defaults_get::<String>(TAG_LANGUAGE_KEY).unwrap_or_else(|| "english".into())
 }
 
+pub fn get_tag_display() -> TagDisplay {
+	defaults_get::<String>(TAG_DISPLAY_KEY)
+		.map(TagDisplay::from)
+		.unwrap_or_default()
+}
+
 pub fn get_blocklist() -> Vec<String> {
 	defaults_get::<Vec<String>>(BLOCKLIST_KEY)
 		.unwrap_or_default()
@@ -60,6 +122,30 @@ pub fn get_blocklist() -> Vec<String> {
 		.collect()
 }
 
+pub fn get_title_blocklist() -> Vec<String> {
+	defaults_get::<Vec<String>>(TITLE_BLOCKLIST_KEY)
+		.unwrap_or_default()
+		.into_iter()
+		.map(|s| s.trim().to_lowercase())
+		.filter(|s| !s.is_empty())
+		.collect()
+}
+
+pub fn get_allowlist() -> Vec<String> {
+	defaults_get::<Vec<String>>(ALLOWLIST_KEY)
+		.unwrap_or_default()
+		.into_iter()
+		.map(|s| s.trim().to_lowercase())
+		.filter(|s| !s.is_empty())
+		.collect()
+}
+
+pub fn get_allowlist_mode() -> AllowlistMode {
+	defaults_get::<String>(ALLOWLIST_MODE_KEY)
+		.map(AllowlistMode::from)
+		.unwrap_or_default()
+}
+
 pub fn get_favorite_tags() -> Vec<String> {
 	defaults_get::<Vec<String>>(FAVORITE_TAGS_KEY)
 		.unwrap_or_default()
@@ -76,3 +162,66 @@ pub fn get_list_viewer() -> bool {
 pub fn get_sort_tags_alphabetically() -> bool {
 	defaults_get(SORT_TAGS_ALPHABETICALLY_KEY).unwrap_or(false)
 }
+
+pub fn get_show_hidden() -> bool {
+	defaults_get(SHOW_HIDDEN_KEY).unwrap_or(false)
+}
+
+pub fn get_min_pages() -> i32 {
+	defaults_get::<f64>(MIN_PAGES_KEY).map_or(0, |value| value as i32)
+}
+
+pub fn get_min_favorites() -> i32 {
+	defaults_get::<f64>(MIN_FAVORITES_KEY).map_or(0, |value| value as i32)
+}
+
+pub fn set_blocklist(blocklist: Vec<String>) {
+	defaults_set(BLOCKLIST_KEY, DefaultValue::StringArray(blocklist));
+}
+
+pub fn set_allowlist(allowlist: Vec<String>) {
+	defaults_set(ALLOWLIST_KEY, DefaultValue::StringArray(allowlist));
+}
+
+pub fn set_favorite_tags(favorite_tags: Vec<String>) {
+	defaults_set(FAVORITE_TAGS_KEY, DefaultValue::StringArray(favorite_tags));
+}
+
+pub fn get_import_lists() -> String {
+	defaults_get::<String>(IMPORT_LISTS_KEY).unwrap_or_default()
+}
+
+pub fn clear_import_lists() {
+	defaults_set(IMPORT_LISTS_KEY, DefaultValue::String(String::new()));
+}
+
+pub fn set_export_lists(value: String) {
+	defaults_set(EXPORT_LISTS_KEY, DefaultValue::String(value));
+}
+
+pub fn get_translation_overrides() -> Vec<String> {
+	defaults_get::<Vec<String>>(TRANSLATION_OVERRIDES_KEY).unwrap_or_default()
+}
+
+pub fn get_translation_db_url() -> String {
+	defaults_get::<String>(TRANSLATION_DB_URL_KEY)
+		.map(|url| url.trim().into())
+		.unwrap_or_default()
+}
+
+pub fn get_translation_db() -> Option<String> {
+	defaults_get::<String>(TRANSLATION_DB_KEY).filter(|json| !json.is_empty())
+}
+
+pub fn set_translation_db(json: Option<String>) {
+	let value = json.map_or(DefaultValue::Null, DefaultValue::String);
+	defaults_set(TRANSLATION_DB_KEY, value);
+}
+
+pub fn set_translation_db_status(status: String) {
+	defaults_set(TRANSLATION_DB_STATUS_KEY, DefaultValue::String(status));
+}
+
+pub fn get_tag_glossary() -> bool {
+	defaults_get(TAG_GLOSSARY_KEY).unwrap_or(true)
+}
@@ -0,0 +1,247 @@
+use crate::settings;
+
+/// User-visible text for one display language
+///
+/// Option lists line up with `LANGUAGES`, `CATEGORIES`, `UPLOAD_AGES` and the
+/// sort filter indices.
+pub struct Strings {
+	pub artist: &'static str,
+	pub artist_placeholder: &'static str,
+	pub group: &'static str,
+	pub group_placeholder: &'static str,
+	pub parody: &'static str,
+	pub parody_placeholder: &'static str,
+	pub character: &'static str,
+	pub character_placeholder: &'static str,
+	pub sort: &'static str,
+	pub sort_options: [&'static str; 4],
+	pub favorite_tags: &'static str,
+	pub tags: &'static str,
+	pub languages: &'static str,
+	pub language_options: [&'static str; 7],
+	pub categories: &'static str,
+	pub category_options: [&'static str; 9],
+	pub pages: &'static str,
+	pub uploaded: &'static str,
+	pub upload_age_options: [&'static str; 5],
+	pub uploaded_days: &'static str,
+	pub uploaded_days_placeholder: &'static str,
+
+	pub popular_today: &'static str,
+	pub popular_week: &'static str,
+	pub popular_all: &'static str,
+	pub latest: &'static str,
+	pub hidden: &'static str,
+	pub reason_language: &'static str,
+	pub reason_title: &'static str,
+	pub reason_allowlist: &'static str,
+	pub reason_pages: &'static str,
+	pub reason_favorites: &'static str,
+
+	pub category_label: &'static str,
+	pub parodies_label: &'static str,
+	pub characters_label: &'static str,
+	pub pages_label: &'static str,
+	pub favorited_by_label: &'static str,
+	pub glossary_label: &'static str,
+
+	/// Translation database status, with `{date}`, `{count}` and `{error}` filled in
+	pub db_disabled: &'static str,
+	pub db_updated: &'static str,
+	pub db_unknown_date: &'static str,
+	pub db_failed: &'static str,
+}
+
+static EN: Strings = Strings {
+	artist: "Artist",
+	artist_placeholder: "Artist name",
+	group: "Group",
+	group_placeholder: "Group name",
+	parody: "Parody",
+	parody_placeholder: "Parody name",
+	character: "Character",
+	character_placeholder: "Character name",
+	sort: "Sort",
+	sort_options: ["Latest", "Popular Today", "Popular Week", "Popular All"],
+	favorite_tags: "Favorite Tags",
+	tags: "Tags",
+	languages: "Languages",
+	language_options: [
+		"English",
+		"Japanese",
+		"Chinese",
+		"Translated",
+		"Rewrite",
+		"Speechless",
+		"Text Cleaned",
+	],
+	categories: "Categories",
+	category_options: [
+		"Doujinshi",
+		"Manga",
+		"Artist CG",
+		"Game CG",
+		"Western",
+		"Non-H",
+		"Image Set",
+		"Cosplay",
+		"Misc",
+	],
+	pages: "Pages",
+	uploaded: "Uploaded",
+	upload_age_options: [
+		"Any Time",
+		"Past Day",
+		"Past Week",
+		"Past Month",
+		"Past Year",
+	],
+	uploaded_days: "Uploaded Within (Days)",
+	uploaded_days_placeholder: "Custom number of days",
+
+	popular_today: "Popular Today",
+	popular_week: "Popular This Week",
+	popular_all: "Popular All Time",
+	latest: "Latest",
+	hidden: "hidden",
+	reason_language: "language",
+	reason_title: "title",
+	reason_allowlist: "allowlist",
+	reason_pages: "pages",
+	reason_favorites: "favorites",
+
+	category_label: "Category",
+	parodies_label: "Parodies",
+	characters_label: "Characters",
+	pages_label: "Pages",
+	favorited_by_label: "Favorited by",
+	glossary_label: "Glossary",
+
+	db_disabled: "Disabled",
+	db_updated: "Updated {date}, {count} entries",
+	db_unknown_date: "unknown date",
+	db_failed: "Update failed: {error}",
+};
+
+static ZH_HANS: Strings = Strings {
+	artist: "画师",
+	artist_placeholder: "画师名称",
+	group: "社团",
+	group_placeholder: "社团名称",
+	parody: "原作",
+	parody_placeholder: "原作名称",
+	character: "角色",
+	character_placeholder: "角色名称",
+	sort: "排序",
+	sort_options: ["最新", "今日热门", "本周热门", "历史热门"],
+	favorite_tags: "收藏标签",
+	tags: "标签",
+	languages: "语言",
+	language_options: ["英语", "日语", "中文", "翻译", "改写", "无文字", "去文字"],
+	categories: "分类",
+	category_options: [
+		"同人志",
+		"漫画",
+		"画师CG",
+		"游戏CG",
+		"西方",
+		"非H",
+		"图集",
+		"Cosplay",
+		"杂项",
+	],
+	pages: "页数",
+	uploaded: "上传时间",
+	upload_age_options: ["不限", "最近一天", "最近一周", "最近一个月", "最近一年"],
+	uploaded_days: "上传天数以内",
+	uploaded_days_placeholder: "自定义天数",
+
+	popular_today: "今日热门",
+	popular_week: "本周热门",
+	popular_all: "历史热门",
+	latest: "最新",
+	hidden: "已隐藏",
+	reason_language: "语言",
+	reason_title: "标题",
+	reason_allowlist: "缺少必需标签",
+	reason_pages: "页数",
+	reason_favorites: "收藏数",
+
+	category_label: "分类",
+	parodies_label: "原作",
+	characters_label: "角色",
+	pages_label: "页数",
+	favorited_by_label: "收藏数",
+	glossary_label: "标签说明",
+
+	db_disabled: "未启用",
+	db_updated: "更新于 {date}，共 {count} 条",
+	db_unknown_date: "未知时间",
+	db_failed: "更新失败：{error}",
+};
+
+static ZH_HANT: Strings = Strings {
+	artist: "畫師",
+	artist_placeholder: "畫師名稱",
+	group: "社團",
+	group_placeholder: "社團名稱",
+	parody: "原作",
+	parody_placeholder: "原作名稱",
+	character: "角色",
+	character_placeholder: "角色名稱",
+	sort: "排序",
+	sort_options: ["最新", "今日熱門", "本週熱門", "歷史熱門"],
+	favorite_tags: "收藏標籤",
+	tags: "標籤",
+	languages: "語言",
+	language_options: ["英語", "日語", "中文", "翻譯", "改寫", "無文字", "去文字"],
+	categories: "分類",
+	category_options: [
+		"同人誌",
+		"漫畫",
+		"畫師CG",
+		"遊戲CG",
+		"西方",
+		"非H",
+		"圖集",
+		"Cosplay",
+		"雜項",
+	],
+	pages: "頁數",
+	uploaded: "上傳時間",
+	upload_age_options: ["不限", "最近一天", "最近一週", "最近一個月", "最近一年"],
+	uploaded_days: "上傳天數以內",
+	uploaded_days_placeholder: "自訂天數",
+
+	popular_today: "今日熱門",
+	popular_week: "本週熱門",
+	popular_all: "歷史熱門",
+	latest: "最新",
+	hidden: "已隱藏",
+	reason_language: "語言",
+	reason_title: "標題",
+	reason_allowlist: "缺少必需標籤",
+	reason_pages: "頁數",
+	reason_favorites: "收藏數",
+
+	category_label: "分類",
+	parodies_label: "原作",
+	characters_label: "角色",
+	pages_label: "頁數",
+	favorited_by_label: "收藏數",
+	glossary_label: "標籤說明",
+
+	db_disabled: "未啟用",
+	db_updated: "更新於 {date}，共 {count} 條",
+	db_unknown_date: "未知時間",
+	db_failed: "更新失敗：{error}",
+};
+
+/// Strings for the selected metadata language, falling back to English
+pub fn get() -> &'static Strings {
+	match settings::get_metadata_language().as_str() {
+		"chinese" => &ZH_HANS,
+		"traditional-chinese" => &ZH_HANT,
+		_ => &EN,
+	}
+}
@@ -0,0 +1,133 @@
+use crate::{blocklist::TagRule, models::reverse_translate_tag, settings};
+use aidoku::alloc::{String, Vec, string::ToString};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+	FavoriteTags,
+	Blocklist,
+	Allowlist,
+}
+
+impl Section {
+	fn parse(value: &str) -> Option<Self> {
+		match value.trim().to_lowercase().as_str() {
+			"favorites" | "favoritetags" | "favorite tags" => Some(Self::FavoriteTags),
+			"blocklist" => Some(Self::Blocklist),
+			"allowlist" => Some(Self::Allowlist),
+			_ => None,
+		}
+	}
+}
+
+/// Favorite tags, blocklist and allowlist bundled for sharing between devices
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct TagLists {
+	#[serde(alias = "favorites")]
+	favorite_tags: Vec<String>,
+	blocklist: Vec<String>,
+	allowlist: Vec<String>,
+}
+
+impl TagLists {
+	fn from_settings() -> Self {
+		Self {
+			favorite_tags: settings::get_favorite_tags(),
+			blocklist: settings::get_blocklist(),
+			allowlist: settings::get_allowlist(),
+		}
+	}
+
+	/// Parse a JSON bundle, or text where each line holds comma separated
+	/// entries optionally prefixed by a list name, e.g. `allowlist: a, b`
+	///
+	/// Entries without a list name go to the blocklist, or to the list named
+	/// on an earlier line.
+	fn parse(input: &str) -> Option<Self> {
+		let input = input.trim();
+		if input.starts_with('{') {
+			return serde_json::from_str(input).ok();
+		}
+
+		let mut lists = Self::default();
+		let mut section = Section::Blocklist;
+		for line in input.lines() {
+			let mut entries = line;
+			if let Some((prefix, rest)) = line.split_once(':')
+				&& let Some(named) = Section::parse(prefix)
+			{
+				section = named;
+				entries = rest;
+			}
+			lists.list_mut(section).extend(
+				entries
+					.split([',', '，'])
+					.map(str::trim)
+					.filter(|entry| !entry.is_empty())
+					.map(String::from),
+			);
+		}
+		Some(lists)
+	}
+
+	fn list_mut(&mut self, section: Section) -> &mut Vec<String> {
+		match section {
+			Section::FavoriteTags => &mut self.favorite_tags,
+			Section::Blocklist => &mut self.blocklist,
+			Section::Allowlist => &mut self.allowlist,
+		}
+	}
+
+	/// Convert localized names to the English names stored in settings
+	fn normalize(self) -> Self {
+		let rule = |entry: &String| TagRule::parse(entry).to_string();
+		Self {
+			favorite_tags: normalize(self.favorite_tags, |tag| {
+				reverse_translate_tag(tag.trim()).to_lowercase()
+			}),
+			blocklist: normalize(self.blocklist, rule),
+			allowlist: normalize(self.allowlist, rule),
+		}
+	}
+}
+
+fn normalize(entries: Vec<String>, f: impl Fn(&String) -> String) -> Vec<String> {
+	merge(Vec::new(), entries.iter().map(f))
+}
+
+/// Append the entries not already in `existing`
+fn merge(mut existing: Vec<String>, entries: impl Iterator<Item = String>) -> Vec<String> {
+	for entry in entries {
+		if !entry.is_empty() && !existing.contains(&entry) {
+			existing.push(entry);
+		}
+	}
+	existing
+}
+
+/// Merge the pasted lists into the current settings
+///
+/// Invalid input is left in place so it can be corrected.
+pub fn import() {
+	let Some(imported) = TagLists::parse(&settings::get_import_lists()) else {
+		return;
+	};
+	let imported = imported.normalize();
+	let current = TagLists::from_settings();
+	settings::set_favorite_tags(merge(
+		current.favorite_tags,
+		imported.favorite_tags.into_iter(),
+	));
+	settings::set_blocklist(merge(current.blocklist, imported.blocklist.into_iter()));
+	settings::set_allowlist(merge(current.allowlist, imported.allowlist.into_iter()));
+	settings::clear_import_lists();
+	export();
+}
+
+/// Render the current lists as JSON into the export field
+pub fn export() {
+	if let Ok(json) = serde_json::to_string(&TagLists::from_settings()) {
+		settings::set_export_lists(json);
+	}
+}
@@ -0,0 +1,215 @@
+use crate::{USER_AGENT, cache::Cache, models::to_simplified, query::Namespace, settings, strings};
+use aidoku::{
+	Result,
+	alloc::{String, Vec, string::ToString},
+	imports::net::Request,
+};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// EhTagTranslation namespaces merged into plain tags, as in `fetch_localization_cn.py`
+const TAG_NAMESPACES: [&str; 5] = ["female", "male", "mixed", "location", "other"];
+
+/// Namespaces kept from the database
+const NAMESPACES: [Namespace; 5] = [
+	Namespace::Tag,
+	Namespace::Parody,
+	Namespace::Character,
+	Namespace::Group,
+	Namespace::Artist,
+];
+
+/// The parts of an EhTagTranslation `db.text.json` release we read
+#[derive(Deserialize)]
+struct Release {
+	head: Option<Head>,
+	data: Vec<ReleaseNamespace>,
+}
+
+#[derive(Deserialize)]
+struct Head {
+	committer: Option<Signature>,
+}
+
+#[derive(Deserialize)]
+struct Signature {
+	when: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ReleaseNamespace {
+	namespace: String,
+	data: HashMap<String, ReleaseEntry>,
+}
+
+#[derive(Deserialize)]
+struct ReleaseEntry {
+	name: Option<String>,
+	intro: Option<String>,
+}
+
+/// Longest tag introduction kept, in characters
+const INTRO_LENGTH: usize = 60;
+
+/// Drop emoji, which the tag views can't show
+fn strip_emoji(text: &str) -> String {
+	text.chars().filter(|c| (*c as u32) < 0x10000).collect()
+}
+
+/// First sentence of an introduction, cut to `INTRO_LENGTH`
+fn short_intro(intro: &str) -> Option<String> {
+	let line = intro.lines().map(str::trim).find(|line| !line.is_empty())?;
+	let sentence = line
+		.find('。')
+		.map_or(line, |end| &line[..end + '。'.len_utf8()]);
+	let sentence = strip_emoji(sentence);
+	let mut short: String = sentence.chars().take(INTRO_LENGTH).collect();
+	if short.len() < sentence.len() {
+		short.push('…');
+	}
+	Some(short)
+}
+
+/// Chinese names downloaded at runtime, layered over the compiled maps
+#[derive(Default, Serialize, Deserialize)]
+pub struct TranslationDb {
+	/// When the database was last changed upstream
+	updated: Option<String>,
+	/// Namespace -> English name -> localized name
+	names: HashMap<String, HashMap<String, String>>,
+	/// English tag name -> short introduction
+	#[serde(default)]
+	intros: HashMap<String, String>,
+	/// Namespace -> folded localized name -> English name
+	#[serde(skip)]
+	reverse: HashMap<String, HashMap<String, String>>,
+}
+
+impl TranslationDb {
+	fn from_release(release: Release) -> Self {
+		let mut names: HashMap<String, HashMap<String, String>> = HashMap::new();
+		let mut intros = HashMap::new();
+		for namespace in release.data {
+			let target = if TAG_NAMESPACES.contains(&namespace.namespace.as_str()) {
+				Namespace::Tag
+			} else {
+				match Namespace::parse(&namespace.namespace) {
+					Some(namespace) if NAMESPACES.contains(&namespace) => namespace,
+					_ => continue,
+				}
+			};
+			let is_tag = target == Namespace::Tag;
+			let target = names.entry(target.as_str().into()).or_default();
+			for (key, entry) in namespace.data {
+				if key.is_empty() {
+					continue;
+				}
+				let name = strip_emoji(&entry.name.unwrap_or_default());
+				let name = name.trim();
+				if !name.is_empty() {
+					target.insert(key.to_lowercase(), name.into());
+				}
+				let intro = entry.intro.as_deref().filter(|_| is_tag);
+				if let Some(intro) = intro.and_then(short_intro) {
+					intros.insert(key.to_lowercase(), intro);
+				}
+			}
+		}
+
+		let mut db = Self {
+			updated: release.head.and_then(|head| head.committer?.when),
+			names,
+			intros,
+			reverse: HashMap::new(),
+		};
+		db.build_reverse();
+		db
+	}
+
+	fn build_reverse(&mut self) {
+		self.reverse = self
+			.names
+			.iter()
+			.map(|(namespace, names)| {
+				let reverse = names
+					.iter()
+					.map(|(english, name)| (to_simplified(&name.to_lowercase()), english.clone()))
+					.collect();
+				(namespace.clone(), reverse)
+			})
+			.collect();
+	}
+
+	fn len(&self) -> usize {
+		self.names.values().map(|names| names.len()).sum()
+	}
+
+	fn load() -> Option<Self> {
+		let json = settings::get_translation_db()?;
+		let mut db: Self = serde_json::from_str(&json).ok()?;
+		db.build_reverse();
+		Some(db)
+	}
+}
+
+/// The database loaded from defaults, if one was downloaded
+static CACHE: Cache<Option<TranslationDb>> = Cache::new();
+
+fn with_db<T>(f: impl FnOnce(&TranslationDb) -> Option<T>) -> Option<T> {
+	CACHE.with(TranslationDb::load, |db| db.as_ref().and_then(f))
+}
+
+/// Localized name for a lowercase English name
+pub fn translate(namespace: Namespace, name: &str) -> Option<String> {
+	with_db(|db| db.names.get(namespace.as_str())?.get(name).cloned())
+}
+
+/// English name for a localized name folded with `to_simplified`
+pub fn reverse(namespace: Namespace, key: &str) -> Option<String> {
+	with_db(|db| db.reverse.get(namespace.as_str())?.get(key).cloned())
+}
+
+/// Short introduction for a lowercase English tag name
+pub fn intro(name: &str) -> Option<String> {
+	with_db(|db| db.intros.get(name).cloned())
+}
+
+fn download(url: &str) -> Result<TranslationDb> {
+	let release: Release = Request::get(url)?
+		.header("User-Agent", USER_AGENT)
+		.json_owned()?;
+	Ok(TranslationDb::from_release(release))
+}
+
+/// Download the database from the configured url, or remove it if the url is empty
+pub fn update() {
+	let strings = strings::get();
+	let url = settings::get_translation_db_url();
+	if url.is_empty() {
+		settings::set_translation_db(None);
+		settings::set_translation_db_status(strings.db_disabled.into());
+		CACHE.set(None);
+		return;
+	}
+
+	let status = match download(&url) {
+		Ok(db) => {
+			let date = db
+				.updated
+				.as_deref()
+				.map(|when| when.get(..10).unwrap_or(when))
+				.unwrap_or(strings.db_unknown_date);
+			let status = strings
+				.db_updated
+				.replace("{date}", date)
+				.replace("{count}", &db.len().to_string());
+			if let Ok(json) = serde_json::to_string(&db) {
+				settings::set_translation_db(Some(json));
+			}
+			CACHE.set(Some(db));
+			status
+		}
+		Err(err) => strings.db_failed.replace("{error}", &err.to_string()),
+	};
+	settings::set_translation_db_status(status);
+}